@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+use crate::interpreter::errors::{ErrorKind, WhileError};
+use crate::interpreter::lexer::methods::{MethodCallToken, MethodToken};
+use crate::interpreter::lexer::models::{AssignableToken, Stackable};
+use crate::interpreter::lexer::parser::Dialect;
+use crate::interpreter::lexer::scopes::TopLevelScope;
+use crate::interpreter::models::{CodeLine, SourceLocation};
+
+/// Checks a parsed program before it runs: every method call must name a defined method and pass
+/// the number of arguments the method expects, and every method must only be defined once.
+/// Programs of the loop dialect must not contain recursive methods either.
+pub struct Analyzer<'a> {
+    methods: HashMap<&'a str, &'a MethodToken>,
+    code_lines: &'a [CodeLine],
+    diagnostics: Vec<WhileError>
+}
+
+impl<'a> Analyzer<'a> {
+    /// `code_lines` are only used to show the affected source lines in the errors.
+    pub fn analyse(scope: &'a TopLevelScope, code_lines: &'a [CodeLine]) -> Result<(), Vec<WhileError>> {
+        return Analyzer::analyse_with_dialect(scope, code_lines, Dialect::Classic);
+    }
+
+    pub fn analyse_with_dialect(scope: &'a TopLevelScope, code_lines: &'a [CodeLine], dialect: Dialect) -> Result<(), Vec<WhileError>> {
+        let mut analyzer = Analyzer {
+            methods: HashMap::new(),
+            code_lines,
+            diagnostics: Vec::new()
+        };
+
+        for method in &scope.methods {
+            analyzer.define(method);
+        }
+
+        for method in &scope.methods {
+            analyzer.check_stack(&method.scope.stack);
+        }
+
+        analyzer.check_stack(&scope.stack);
+
+        if dialect == Dialect::Loop {
+            for method in &scope.methods {
+                analyzer.check_recursion(method);
+            }
+        }
+
+        if !analyzer.diagnostics.is_empty() {
+            analyzer.diagnostics.sort_by_key(|diagnostic| diagnostic.line);
+            return Err(analyzer.diagnostics);
+        }
+
+        return Ok(());
+    }
+
+    fn define(&mut self, method: &'a MethodToken) {
+        let name = method.header_token.name.value.as_str();
+
+        if let Some(defined) = self.methods.get(name) {
+            let message = format!("Method {} is already defined at line {}", name, defined.location.line);
+            self.diagnostics.push(self.error(ErrorKind::DuplicateMethod, message, method.location));
+            return;
+        }
+
+        self.methods.insert(name, method);
+    }
+
+    fn check_stack(&mut self, stack: &[Stackable]) {
+        for stackable in stack {
+            match stackable {
+                Stackable::MethodCallToken { value } => self.check_method_call(value),
+                Stackable::VariableToken { value } => self.check_assignable(&value.assignment),
+                Stackable::OperatorToken { value } => self.check_assignable(&value.rhs_operand),
+                Stackable::WritelnToken { value } => self.check_assignable(&value.value),
+                Stackable::LoopToken { value } => self.check_stack(&value.scope.stack),
+                Stackable::IfToken { value } => {
+                    for assignable in value.condition.assignables() {
+                        self.check_assignable(assignable);
+                    }
+
+                    self.check_stack(&value.scope.stack);
+
+                    if let Some(else_scope) = &value.else_scope {
+                        self.check_stack(&else_scope.stack);
+                    }
+                }
+                Stackable::ReturnToken { value } => {
+                    if let Some(return_value) = &value.return_value {
+                        self.check_assignable(return_value);
+                    }
+                }
+                Stackable::LoopControlToken { .. } => { }
+                Stackable::WhileToken { value } => {
+                    for assignable in value.header_token.condition.assignables() {
+                        self.check_assignable(assignable);
+                    }
+
+                    if let Some(scope) = &value.scope {
+                        self.check_stack(&scope.stack);
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_assignable(&mut self, assignable: &AssignableToken) {
+        match assignable {
+            AssignableToken::MethodCall { value } => self.check_method_call(value),
+            AssignableToken::Expression { value } => {
+                self.check_assignable(&value.lhs);
+                self.check_assignable(&value.rhs);
+            }
+            AssignableToken::Name { .. } | AssignableToken::Digit { .. } => { }
+        }
+    }
+
+    fn check_method_call(&mut self, method_call: &MethodCallToken) {
+        for parameter in &method_call.parameters {
+            self.check_assignable(parameter);
+        }
+
+        let name = method_call.name.value.as_str();
+
+        let expected = match self.methods.get(name) {
+            Some(method) => method.header_token.parameters.len(),
+            None => {
+                self.diagnostics.push(self.error(ErrorKind::MethodNotFound, format!("Method not found: \"{}\"", name), method_call.location));
+                return;
+            }
+        };
+
+        if expected != method_call.parameters.len() {
+            let message = format!("Method {} expects {} arguments, but got {}", name, expected, method_call.parameters.len());
+            self.diagnostics.push(self.error(ErrorKind::WrongArgumentCount, message, method_call.location));
+        }
+    }
+
+    /// Reports a method that can call itself, directly or through other methods.
+    fn check_recursion(&mut self, method: &'a MethodToken) {
+        let name = method.header_token.name.value.as_str();
+
+        // only the definition that is called is checked, duplicates are already reported
+        if !self.methods.get(name).is_some_and(|defined| std::ptr::eq(*defined, method)) {
+            return;
+        }
+
+        let mut path = vec![name];
+
+        if self.find_call_path(name, &mut path) {
+            let message = format!("Method {} is recursive ({}), which a LOOP program can't be", name, path.join(" -> "));
+            self.diagnostics.push(self.error(ErrorKind::Recursion, message, method.location));
+        }
+    }
+
+    /// Searches a chain of calls from the last method of `path` back to `target`.
+    fn find_call_path(&self, target: &str, path: &mut Vec<&'a str>) -> bool {
+        let method = match self.methods.get(path[path.len() - 1]) {
+            Some(method) => *method,
+            None => return false
+        };
+
+        let mut calls = Vec::new();
+        Analyzer::collect_calls(&method.scope.stack, &mut calls);
+
+        for call in calls {
+            let name = call.name.value.as_str();
+
+            if name == target {
+                path.push(name);
+                return true;
+            }
+
+            if path.contains(&name) {
+                continue;
+            }
+
+            path.push(name);
+
+            if self.find_call_path(target, path) {
+                return true;
+            }
+
+            path.pop();
+        }
+
+        return false;
+    }
+
+    fn collect_calls(stack: &'a [Stackable], calls: &mut Vec<&'a MethodCallToken>) {
+        for stackable in stack {
+            let assignables = match stackable {
+                Stackable::MethodCallToken { value } => {
+                    calls.push(value);
+                    value.parameters.iter().collect()
+                }
+                Stackable::VariableToken { value } => vec![&value.assignment],
+                Stackable::OperatorToken { value } => vec![&value.rhs_operand],
+                Stackable::WritelnToken { value } => vec![&value.value],
+                Stackable::ReturnToken { value } => value.return_value.iter().collect(),
+                Stackable::LoopControlToken { .. } => vec![],
+                Stackable::WhileToken { value } => {
+                    if let Some(scope) = &value.scope {
+                        Analyzer::collect_calls(&scope.stack, calls);
+                    }
+
+                    value.header_token.condition.assignables()
+                }
+                Stackable::LoopToken { value } => {
+                    Analyzer::collect_calls(&value.scope.stack, calls);
+                    vec![]
+                }
+                Stackable::IfToken { value } => {
+                    Analyzer::collect_calls(&value.scope.stack, calls);
+
+                    if let Some(else_scope) = &value.else_scope {
+                        Analyzer::collect_calls(&else_scope.stack, calls);
+                    }
+
+                    value.condition.assignables()
+                }
+            };
+
+            for assignable in assignables {
+                Analyzer::collect_assignable_calls(assignable, calls);
+            }
+        }
+    }
+
+    fn collect_assignable_calls(assignable: &'a AssignableToken, calls: &mut Vec<&'a MethodCallToken>) {
+        match assignable {
+            AssignableToken::MethodCall { value } => {
+                calls.push(value);
+
+                for parameter in &value.parameters {
+                    Analyzer::collect_assignable_calls(parameter, calls);
+                }
+            }
+            AssignableToken::Expression { value } => {
+                Analyzer::collect_assignable_calls(&value.lhs, calls);
+                Analyzer::collect_assignable_calls(&value.rhs, calls);
+            }
+            AssignableToken::Name { .. } | AssignableToken::Digit { .. } => { }
+        }
+    }
+
+    fn error(&self, kind: ErrorKind, message: String, location: SourceLocation) -> WhileError {
+        return match self.code_lines.iter().find(|code_line| code_line.line_number == location.line) {
+            Some(code_line) => WhileError::at_column(kind, message, code_line, location.column),
+            None => WhileError::new(kind, message, location.line, location.column)
+        };
+    }
+}
@@ -0,0 +1,3 @@
+mod analyzer;
+
+pub use analyzer::Analyzer;
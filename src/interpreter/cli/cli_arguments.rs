@@ -0,0 +1,151 @@
+use crate::interpreter::cli::{Command, Source};
+use crate::interpreter::executor_states::{NumericMode, RunTimeOptions, UnderflowMode};
+use crate::interpreter::lexer::parser::Dialect;
+use crate::interpreter::utils::logging::Logger;
+
+/// The parsed command line, see `USAGE`. Options may come before or after the file, their value
+/// is the next argument or follows a `=`. Every argument that doesn't start with `-` is taken as it
+/// is, so paths may contain dashes and `=`. The path `-` reads the program from stdin, `-e` takes
+/// the code of the program instead of a file.
+pub struct CliArguments {
+    pub command: Command,
+    pub source: Option<Source>,
+    pub dialect: Dialect,
+    pub options: RunTimeOptions,
+    pub logger: Logger,
+    pub check: bool,
+    /// The global variable whose value becomes the exit code after a successful run.
+    pub result: Option<String>
+}
+
+impl CliArguments {
+    /// Parses the arguments without the name of the binary. The error describes the invalid argument.
+    pub fn parse(args: &[String]) -> Result<CliArguments, String> {
+        let mut arguments = CliArguments {
+            command: Command::Run,
+            source: None,
+            dialect: Dialect::Classic,
+            options: RunTimeOptions::default(),
+            logger: Logger::NoLogger,
+            check: false,
+            result: None
+        };
+
+        let mut command = None;
+        let mut only_files = false;
+        let mut index = 0;
+
+        while index < args.len() {
+            let arg = args[index].as_str();
+            index += 1;
+
+            if only_files || !arg.starts_with('-') || arg == "-" {
+                if command.is_none() && arguments.source.is_none() {
+                    if let Some(analysed) = Command::analyse(arg).filter(|_| !only_files) {
+                        command = Some(analysed);
+                        continue;
+                    }
+                }
+
+                CliArguments::set_source(&mut arguments, Source::File(arg.to_string()), arg)?;
+                continue;
+            }
+
+            let (name, inline_value) = match arg.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (arg, None)
+            };
+
+            match name {
+                "--" => only_files = true,
+                "-h" | "--help" => command = Some(Command::Help),
+                "--log" | "--check" => {
+                    if inline_value.is_some() {
+                        return Err(format!("The option {} doesn't take a value", name));
+                    }
+
+                    match name {
+                        "--log" => arguments.logger = Logger::StdLogger,
+                        _ => arguments.check = true
+                    }
+                }
+                "-e" | "--eval" | "--dialect" | "--numeric" | "--underflow" | "--result" => {
+                    let value = match inline_value {
+                        Some(value) => value,
+                        None if index < args.len() => {
+                            index += 1;
+                            args[index - 1].clone()
+                        }
+                        None => return Err(format!("The option {} needs a value", name))
+                    };
+
+                    CliArguments::option(&mut arguments, name, &value)?;
+                }
+                _ => return Err(format!("Unknown option \"{}\"", arg))
+            }
+        }
+
+        arguments.command = command.unwrap_or(Command::Run);
+
+        if arguments.command == Command::Help {
+            return Ok(arguments);
+        }
+
+        if arguments.command.needs_source() && arguments.source.is_none() {
+            return Err("No source file given, use a path, - for stdin or -e with the code".to_string());
+        }
+
+        if arguments.command == Command::Repl && arguments.source.as_ref().is_some_and(Source::is_stdin) {
+            return Err("The REPL reads its input from stdin, it can't read a program from there".to_string());
+        }
+
+        if arguments.check && arguments.command != Command::Desugar {
+            return Err("The option --check only applies to desugar".to_string());
+        }
+
+        if arguments.result.is_some() && !matches!(arguments.command, Command::Run | Command::Trace) {
+            return Err("The option --result only applies to run and trace".to_string());
+        }
+
+        return Ok(arguments);
+    }
+
+    fn set_source(arguments: &mut CliArguments, source: Source, arg: &str) -> Result<(), String> {
+        if arguments.source.is_some() {
+            return Err(format!("Unexpected argument \"{}\", only one program can be given", arg));
+        }
+
+        arguments.source = Some(source);
+        return Ok(());
+    }
+
+    fn option(arguments: &mut CliArguments, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "-e" | "--eval" => return CliArguments::set_source(arguments, Source::Inline(value.to_string()), name),
+            "--result" => {
+                arguments.result = Some(value.to_string());
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        let value = value.to_lowercase();
+
+        match name {
+            "--dialect" => {
+                arguments.dialect = Dialect::analyse(&value)
+                    .ok_or(format!("Unknown dialect \"{}\", use classic, extended or loop", value))?;
+            }
+            "--numeric" => {
+                arguments.options.numeric = NumericMode::analyse(&value)
+                    .ok_or(format!("Unknown numeric mode \"{}\", use natural or u8, u16, u32, u64, i64 with -wrap, -saturate or -trap, e.g. u8-wrap", value))?;
+            }
+            _ => {
+                arguments.options.underflow = UnderflowMode::analyse(&value)
+                    .ok_or(format!("Unknown underflow mode \"{}\", use saturate or error", value))?;
+            }
+        }
+
+        return Ok(());
+    }
+}
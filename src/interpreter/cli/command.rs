@@ -0,0 +1,35 @@
+/// What the binary does with the program, the first argument of the command line.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Command {
+    Run,
+    Check,
+    Ast,
+    Fmt,
+    Trace,
+    Expand,
+    Desugar,
+    Repl,
+    Help,
+}
+
+impl Command {
+    pub fn analyse(name: &str) -> Option<Command> {
+        return match name {
+            "run" => Some(Command::Run),
+            "check" => Some(Command::Check),
+            "ast" => Some(Command::Ast),
+            "fmt" => Some(Command::Fmt),
+            "trace" => Some(Command::Trace),
+            "expand" => Some(Command::Expand),
+            "desugar" => Some(Command::Desugar),
+            "repl" => Some(Command::Repl),
+            "help" => Some(Command::Help),
+            _ => None
+        }
+    }
+
+    /// Whether the command needs a program, the REPL can also start without one.
+    pub fn needs_source(&self) -> bool {
+        return !matches!(self, Command::Repl | Command::Help);
+    }
+}
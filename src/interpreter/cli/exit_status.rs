@@ -0,0 +1,68 @@
+use std::process::ExitCode;
+use crate::interpreter::models::Value;
+
+/// How the binary ends. Every kind of failure has its own exit code, so scripts can tell them apart.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExitStatus {
+    Success,
+    /// A runtime error stopped the program.
+    Runtime,
+    /// The command line is invalid.
+    Usage,
+    /// The program doesn't parse.
+    Syntax,
+    /// The program parses, but the analysis found errors.
+    Semantic,
+    /// The source can't be read.
+    Io,
+    /// The desugared program isn't equivalent to the program.
+    NotEquivalent,
+    /// `fmt` refuses the program, it has comments or macro definitions the layout can't keep.
+    Unformattable,
+    /// `--result` was given, the exit code is the value of the variable.
+    Result(u8),
+}
+
+/// With `--result` the value takes the codes below this, the failures are moved above it.
+const RESULT_RANGE: u8 = 128;
+
+impl ExitStatus {
+    /// The value as an exit code, modulo 128 so it can't be mistaken for a failure.
+    pub fn result(value: &Value) -> ExitStatus {
+        let code = (value.magnitude().low_u64() % RESULT_RANGE as u64) as u8;
+
+        return match value.is_negative() {
+            true => ExitStatus::Result((RESULT_RANGE - code) % RESULT_RANGE),
+            false => ExitStatus::Result(code)
+        };
+    }
+
+    /// The exit code if `--result` was given: a failure exits with 128 plus its usual code, like a
+    /// shell reports a signal, so it never overlaps with a value.
+    pub fn code_with_result(&self) -> u8 {
+        return match self {
+            ExitStatus::Success | ExitStatus::Result(_) => self.code(),
+            _ => RESULT_RANGE + self.code()
+        };
+    }
+
+    pub fn code(&self) -> u8 {
+        return match self {
+            ExitStatus::Success => 0,
+            ExitStatus::Runtime => 1,
+            ExitStatus::Usage => 2,
+            ExitStatus::Syntax => 3,
+            ExitStatus::Semantic => 4,
+            ExitStatus::Io => 5,
+            ExitStatus::NotEquivalent => 6,
+            ExitStatus::Unformattable => 7,
+            ExitStatus::Result(code) => *code
+        };
+    }
+}
+
+impl From<ExitStatus> for ExitCode {
+    fn from(status: ExitStatus) -> Self {
+        return ExitCode::from(status.code());
+    }
+}
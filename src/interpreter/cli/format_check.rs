@@ -0,0 +1,27 @@
+use crate::interpreter::errors::{ErrorKind, WhileError};
+use crate::interpreter::lexer::scanner::Scanner;
+use crate::interpreter::macros::MacroDefinition;
+use crate::interpreter::models::CodeLine;
+
+/// Checks that `fmt` can print the program without losing anything. The layout is printed from the
+/// tree, which has neither the comments nor the macro definitions, so source with them is refused
+/// instead of being shortened silently.
+pub fn check_formattable(code_lines: &[CodeLine]) -> Result<(), Vec<WhileError>> {
+    let mut errors = Vec::new();
+
+    for code_line in code_lines {
+        if MacroDefinition::at_definition(&Scanner::scan_code_line(code_line)) {
+            errors.push(WhileError::at(ErrorKind::Unformattable, "fmt can't keep macro definitions, use expand to print the program without them".to_string(), code_line));
+        }
+
+        if let Some(index) = code_line.line.find("//") {
+            let column = (code_line.line[..index].chars().count() + 1) as u32;
+            errors.push(WhileError::at_column(ErrorKind::Unformattable, "fmt can't keep comments".to_string(), code_line, column));
+        }
+    }
+
+    return match errors.is_empty() {
+        true => Ok(()),
+        false => Err(errors)
+    };
+}
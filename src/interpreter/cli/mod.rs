@@ -0,0 +1,13 @@
+mod command;
+mod cli_arguments;
+mod exit_status;
+mod format_check;
+mod source;
+mod usage;
+
+pub use command::Command;
+pub use cli_arguments::CliArguments;
+pub use exit_status::ExitStatus;
+pub use format_check::check_formattable;
+pub use source::Source;
+pub use usage::USAGE;
@@ -0,0 +1,34 @@
+use std::fmt::{Display, Formatter};
+use crate::interpreter::errors::WhileError;
+use crate::interpreter::models::CodeLine;
+use crate::interpreter::{read, split};
+
+/// Where the program comes from: a file, stdin for the path `-`, or the code given with `-e`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Source {
+    File(String),
+    Inline(String)
+}
+
+impl Source {
+    pub fn read(&self) -> Result<Vec<CodeLine>, WhileError> {
+        return match self {
+            Source::File(path) => read(path),
+            Source::Inline(code) => Ok(split(code))
+        };
+    }
+
+    pub fn is_stdin(&self) -> bool {
+        return matches!(self, Source::File(path) if path == "-");
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::File(path) if path == "-" => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path),
+            Source::Inline(_) => write!(f, "-e")
+        }
+    }
+}
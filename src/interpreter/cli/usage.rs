@@ -0,0 +1,46 @@
+pub static USAGE: &str = "\
+Usage: while-interpreter <command> [options] <file>
+       while-interpreter [options] <file>            same as run
+
+The file - reads the program from stdin, -e <code> runs the code instead of a file.
+
+Commands:
+  run <file>        parses, checks and runs the program
+  check <file>      parses and checks the program without running it
+  ast <file>        prints the tree of the program
+  fmt <file>        prints the program in a uniform layout, refuses comments and macros
+  trace <file>      runs the program and logs every step
+  expand <file>     prints the program after the macros are expanded
+  desugar <file>    prints the program in core While, --check compares both instead
+  repl [file]       starts an interactive session, after running the program if one is given
+  help              prints this help
+
+Options:
+  --dialect <classic|extended|loop>     the accepted language, classic by default
+  --numeric <mode>                      natural by default, or u8, u16, u32, u64, i64
+                                        with -wrap, -saturate or -trap, e.g. u8-wrap
+  --underflow <saturate|error>          what a natural subtraction below zero does
+  --log                                 logs the lexer output and the final variables
+  --check                               for desugar, checks the core program is equivalent
+  --result <variable>                   for run and trace, exits with the value of the global
+                                        variable after the program, modulo 128
+  -e, --eval <code>                     the program to use instead of a file
+  -h, --help                            prints this help
+
+Options take their value as the next argument or after a \"=\", e.g. --dialect=extended.
+Arguments after \"--\" are files, even if they start with \"-\".
+
+Diagnostics are written to stderr.
+
+Exit codes:
+  0  success
+  1  runtime error
+  2  invalid command line
+  3  syntax error
+  4  semantic error
+  5  the file or stdin can't be read
+  6  the desugared program isn't equivalent
+  7  fmt refuses the program, it has comments or macro definitions
+
+With --result the value takes the codes 0 to 127, a failure exits with 128 plus its code,
+e.g. 131 for a syntax error. An invalid command line is always 2.";
@@ -1,3 +0,0 @@
-pub static KEYWORDS: [&str; 6] = [
-    "num", "void", "writeln", "while", "return", "#"
-];
\ No newline at end of file
@@ -0,0 +1,563 @@
+use std::collections::HashSet;
+use crate::interpreter::lexer::assignables::{DigitToken, NameToken};
+use crate::interpreter::lexer::conditions::{Comparator, ConditionToken};
+use crate::interpreter::lexer::expressions::ArithmeticOperator;
+use crate::interpreter::lexer::if_tokens::IfToken;
+use crate::interpreter::lexer::loop_control::LoopControlToken;
+use crate::interpreter::lexer::loop_tokens::LoopToken;
+use crate::interpreter::lexer::methods::{MethodCallToken, MethodHeaderToken, MethodToken, ReturnToken};
+use crate::interpreter::lexer::models::{AssignableToken, Stackable};
+use crate::interpreter::lexer::operators::OperatorToken;
+use crate::interpreter::lexer::output::WritelnToken;
+use crate::interpreter::lexer::scanner::{Scanner, SourceTokenKind};
+use crate::interpreter::lexer::scopes::{InnerBodyScope, TopLevelScope};
+use crate::interpreter::lexer::variables::VariableToken;
+use crate::interpreter::lexer::while_tokens::{WhileHeaderToken, WhileToken};
+use crate::interpreter::models::{CodeLine, Natural, SourceLocation};
+use crate::interpreter::utils::logging::{Logger, SourceViewElement};
+
+/// Rewrites a program into core While, which only consists of
+///
+/// ```text
+/// x = y;  x = 5;  x += 1;  x -= 1;  while x != 0: ... #
+/// ```
+///
+/// together with methods, calls with variables as arguments, `writeln(x);` and `return x;`.
+///
+/// Intermediate values are kept in fresh variables `t_1`, `t_2`, ..., skipping the names the program
+/// already uses. Every operand is copied into a fresh variable before it's used, so a method call
+/// can't change a value that was evaluated before it. The desugared program computes
+/// the same values with natural numbers, only a division by zero doesn't stop with an error but
+/// runs forever.
+///
+/// `break` and `continue` clear a flag of their loop, the statements after a statement that may
+/// jump only run while the flag is set. A break also clears the variable that keeps the loop going.
+pub struct Desugarer {
+    names: HashSet<String>,
+    temporaries: HashSet<String>,
+    header: Option<MethodHeaderToken>,
+    loops: Vec<Option<Jumps>>
+}
+
+/// The flags of a loop whose body jumps. `active` is 1 while the current iteration runs, `stop`
+/// is set to 0 by a break.
+#[derive(Clone)]
+struct Jumps {
+    active: NameToken,
+    stop: Option<NameToken>
+}
+
+impl Desugarer {
+    pub fn new(scope: &TopLevelScope) -> Self {
+        let code_lines = scope.to_source().iter().map(|line| CodeLine::new_from_line(line)).collect::<Vec<CodeLine>>();
+
+        let names = Scanner::scan(&code_lines).into_iter().filter_map(|token| match token.kind {
+            SourceTokenKind::Name(name) => Some(name),
+            _ => None
+        }).collect();
+
+        Desugarer {
+            names,
+            temporaries: HashSet::new(),
+            header: None,
+            loops: Vec::new()
+        }
+    }
+
+    pub fn desugar(&mut self, scope: &TopLevelScope) -> TopLevelScope {
+        let mut desugared = TopLevelScope::new(Logger::NoLogger);
+
+        for method in &scope.methods {
+            self.header = Some(method.header_token.clone());
+            let body = self.statements(&method.scope.stack);
+
+            desugared.methods.push(MethodToken::new(method.header_token.clone(), self.scope(body), method.location));
+        }
+
+        self.header = None;
+        desugared.stack = self.statements(&scope.stack);
+
+        return desugared;
+    }
+
+    /// Whether the variable was introduced by the desugaring.
+    pub fn is_temporary(&self, name: &str) -> bool {
+        return self.temporaries.contains(name);
+    }
+
+    fn statements(&mut self, stack: &[Stackable]) -> Vec<Stackable> {
+        let mut statements = Vec::new();
+
+        for (index, stackable) in stack.iter().enumerate() {
+            self.statement(stackable, &mut statements);
+
+            if index + 1 < stack.len() && Desugarer::contains_jump(std::slice::from_ref(stackable), |_| true) {
+                let rest = self.statements(&stack[index + 1..]);
+                self.unless_jumped(rest, &mut statements);
+                break;
+            }
+        }
+
+        return statements;
+    }
+
+    /// Runs the statements only if the current iteration of the innermost loop didn't jump.
+    fn unless_jumped(&mut self, stack: Vec<Stackable>, statements: &mut Vec<Stackable>) {
+        let Some(Some(jumps)) = self.loops.last().cloned() else {
+            statements.extend(stack);
+            return;
+        };
+
+        let guard = self.operand(&Desugarer::name(&jumps.active), statements);
+
+        let mut body = vec![Desugarer::assign(&guard, Desugarer::number(0))];
+        body.extend(stack);
+        statements.push(self.while_not_zero(&guard, body));
+    }
+
+    fn statement(&mut self, stackable: &Stackable, statements: &mut Vec<Stackable>) {
+        match stackable {
+            Stackable::VariableToken { value } => {
+                let assignment = match &value.assignment {
+                    AssignableToken::Name { .. } | AssignableToken::Digit { .. } => value.assignment.clone(),
+                    AssignableToken::MethodCall { value } => AssignableToken::MethodCall { value: self.method_call(value, statements) },
+                    assignable => Desugarer::name(&self.operand(assignable, statements))
+                };
+
+                statements.push(Desugarer::assign(&value.name, assignment));
+            }
+            Stackable::OperatorToken { value } => self.operator(value, statements),
+            Stackable::WhileToken { value } => self.while_loop(value, statements),
+            Stackable::LoopToken { value } => self.loop_block(value, statements),
+            Stackable::IfToken { value } => self.if_block(value, statements),
+            Stackable::MethodCallToken { value } => {
+                let method_call_token = self.method_call(value, statements);
+                statements.push(Stackable::MethodCallToken { value: method_call_token });
+            }
+            Stackable::WritelnToken { value } => {
+                let name = self.variable(&value.value, statements);
+                statements.push(Stackable::WritelnToken { value: WritelnToken { value: Desugarer::name(&name), location: value.location } });
+            }
+            Stackable::LoopControlToken { value } => {
+                if let Some(Some(jumps)) = self.loops.last() {
+                    statements.push(Desugarer::assign(&jumps.active, Desugarer::number(0)));
+
+                    if let (LoopControlToken::Break, Some(stop)) = (value, &jumps.stop) {
+                        statements.push(Desugarer::assign(stop, Desugarer::number(0)));
+                    }
+                }
+            }
+            Stackable::ReturnToken { value } => {
+                let return_value = value.return_value.as_ref().map(|assignable| Desugarer::name(&self.variable(assignable, statements)));
+                statements.push(Stackable::ReturnToken { value: ReturnToken { return_value, header: value.header.clone(), location: value.location } });
+            }
+        }
+    }
+
+    /// `x += 1` and `x -= 1` are core, other steps are repeated. The other operators compute the new
+    /// value first, then count the variable down to zero and up to the new value, so the operator
+    /// still changes the global variable if a method has no local one.
+    fn operator(&mut self, operator_token: &OperatorToken, statements: &mut Vec<Stackable>) {
+        let name = &operator_token.name;
+        let operator = operator_token.operator;
+
+        if matches!(operator, ArithmeticOperator::Add | ArithmeticOperator::Sub) {
+            if Desugarer::is_one(&operator_token.rhs_operand) {
+                statements.push(Desugarer::step(name, operator));
+                return;
+            }
+
+            let rhs = self.operand(&operator_token.rhs_operand, statements);
+            statements.push(self.while_not_zero(&rhs, vec![Desugarer::step(name, operator), Desugarer::step(&rhs, ArithmeticOperator::Sub)]));
+            return;
+        }
+
+        let rhs = self.operand(&operator_token.rhs_operand, statements);
+        let lhs = self.operand(&Desugarer::name(name), statements);
+        let value = self.arithmetic(operator, lhs, rhs, statements);
+
+        statements.push(self.while_not_zero(name, vec![Desugarer::step(name, ArithmeticOperator::Sub)]));
+        statements.push(self.while_not_zero(&value, vec![Desugarer::step(name, ArithmeticOperator::Add), Desugarer::step(&value, ArithmeticOperator::Sub)]));
+    }
+
+    /// A classic loop keeps its variable, any other condition is computed into a flag before the
+    /// loop and again at the end of every iteration. The flag is always used if the body breaks,
+    /// it's only computed again if the iteration didn't break.
+    fn while_loop(&mut self, while_token: &WhileToken, statements: &mut Vec<Stackable>) {
+        let stack = while_token.scope.as_ref().map_or(Vec::new(), |scope| scope.stack.clone());
+        let condition = &while_token.header_token.condition;
+
+        if Desugarer::contains_jump(&stack, |jump| jump == LoopControlToken::Break) {
+            let running = self.fresh();
+            let flag = self.loop_condition(condition, statements);
+
+            let mut body = vec![Desugarer::assign(&running, Desugarer::number(1))];
+            body.extend(self.loop_body(&stack, Some(running.clone())));
+            body.push(Desugarer::assign(&flag, Desugarer::number(0)));
+
+            let mut next_body = vec![Desugarer::assign(&running, Desugarer::number(0))];
+            let next = self.loop_condition(condition, &mut next_body);
+            next_body.push(Desugarer::assign(&flag, Desugarer::name(&next)));
+            body.push(self.while_not_zero(&running, next_body));
+
+            statements.push(self.while_not_zero(&flag, body));
+            return;
+        }
+
+        if let Some(name) = Desugarer::against_zero(condition) {
+            let body = self.loop_body(&stack, None);
+            statements.push(self.while_not_zero(&name, body));
+            return;
+        }
+
+        let flag = self.condition(condition, statements);
+        let mut body = self.loop_body(&stack, None);
+        let next = self.condition(condition, &mut body);
+        body.push(Desugarer::assign(&flag, Desugarer::name(&next)));
+
+        statements.push(self.while_not_zero(&flag, body));
+    }
+
+    /// A break sets the counter to zero, so the loop ends after the current iteration.
+    fn loop_block(&mut self, loop_token: &LoopToken, statements: &mut Vec<Stackable>) {
+        let counter = self.operand(&Desugarer::name(&loop_token.counter), statements);
+
+        let mut body = self.loop_body(&loop_token.scope.stack, Some(counter.clone()));
+        body.push(Desugarer::step(&counter, ArithmeticOperator::Sub));
+
+        statements.push(self.while_not_zero(&counter, body));
+    }
+
+    /// The statements of a loop body. If the body jumps, a fresh flag is set at the start of every iteration.
+    fn loop_body(&mut self, stack: &[Stackable], stop: Option<NameToken>) -> Vec<Stackable> {
+        let jumps = match Desugarer::contains_jump(stack, |_| true) {
+            true => Some(Jumps { active: self.fresh(), stop }),
+            false => None
+        };
+
+        let mut body = Vec::new();
+
+        if let Some(jumps) = &jumps {
+            body.push(Desugarer::assign(&jumps.active, Desugarer::number(1)));
+        }
+
+        self.loops.push(jumps);
+        body.extend(self.statements(stack));
+        self.loops.pop();
+
+        return body;
+    }
+
+    /// Whether the statements contain a matching jump to their loop, nested loops are jumps of their own.
+    fn contains_jump(stack: &[Stackable], matches: fn(LoopControlToken) -> bool) -> bool {
+        return stack.iter().any(|stackable| match stackable {
+            Stackable::LoopControlToken { value } => matches(*value),
+            Stackable::IfToken { value } => Desugarer::contains_jump(&value.scope.stack, matches)
+                || value.else_scope.as_ref().is_some_and(|else_scope| Desugarer::contains_jump(&else_scope.stack, matches)),
+            _ => false
+        });
+    }
+
+    /// The branches are loops that run at most once, the else branch runs if the then branch didn't.
+    fn if_block(&mut self, if_token: &IfToken, statements: &mut Vec<Stackable>) {
+        let flag = match Desugarer::against_zero(&if_token.condition) {
+            Some(name) => self.operand(&Desugarer::name(&name), statements),
+            None => self.condition(&if_token.condition, statements)
+        };
+
+        let mut body = vec![Desugarer::assign(&flag, Desugarer::number(0))];
+
+        let Some(else_scope) = &if_token.else_scope else {
+            body.extend(self.statements(&if_token.scope.stack));
+            statements.push(self.while_not_zero(&flag, body));
+            return;
+        };
+
+        let otherwise = self.fresh();
+        statements.push(Desugarer::assign(&otherwise, Desugarer::number(1)));
+
+        body.push(Desugarer::assign(&otherwise, Desugarer::number(0)));
+        body.extend(self.statements(&if_token.scope.stack));
+        statements.push(self.while_not_zero(&flag, body));
+
+        let mut else_body = vec![Desugarer::assign(&otherwise, Desugarer::number(0))];
+        else_body.extend(self.statements(&else_scope.stack));
+        statements.push(self.while_not_zero(&otherwise, else_body));
+    }
+
+    /// Like `condition`, but a classic condition only copies its variable.
+    fn loop_condition(&mut self, condition: &ConditionToken, statements: &mut Vec<Stackable>) -> NameToken {
+        return match Desugarer::against_zero(condition) {
+            Some(name) => {
+                let value = self.operand(&Desugarer::name(&name), statements);
+                self.not_zero(value, statements)
+            }
+            None => self.condition(condition, statements)
+        };
+    }
+
+    /// Computes the condition into a fresh variable, which is 1 if the condition holds and 0 otherwise.
+    fn condition(&mut self, condition: &ConditionToken, statements: &mut Vec<Stackable>) -> NameToken {
+        return match condition {
+            ConditionToken::Comparison { lhs, comparator, rhs } => {
+                let lhs = self.operand(lhs, statements);
+                let rhs = self.operand(rhs, statements);
+
+                match comparator {
+                    Comparator::NotEqual | Comparator::Equal => {
+                        let greater = self.difference(&lhs, &rhs, statements);
+                        let less = self.difference(&rhs, &lhs, statements);
+                        let distance = self.arithmetic(ArithmeticOperator::Add, greater, less, statements);
+
+                        match comparator {
+                            Comparator::NotEqual => self.not_zero(distance, statements),
+                            _ => self.zero(distance, statements)
+                        }
+                    }
+                    Comparator::Less => {
+                        let difference = self.difference(&rhs, &lhs, statements);
+                        self.not_zero(difference, statements)
+                    }
+                    Comparator::LessEqual => {
+                        let difference = self.difference(&lhs, &rhs, statements);
+                        self.zero(difference, statements)
+                    }
+                    Comparator::Greater => {
+                        let difference = self.difference(&lhs, &rhs, statements);
+                        self.not_zero(difference, statements)
+                    }
+                    Comparator::GreaterEqual => {
+                        let difference = self.difference(&rhs, &lhs, statements);
+                        self.zero(difference, statements)
+                    }
+                }
+            }
+            ConditionToken::Not { value } => {
+                let flag = self.condition(value, statements);
+                self.zero(flag, statements)
+            }
+            // the right side is only evaluated if the left side doesn't decide the result
+            ConditionToken::And { lhs, rhs } => {
+                let flag = self.condition(lhs, statements);
+                let result = self.fresh();
+                statements.push(Desugarer::assign(&result, Desugarer::number(0)));
+
+                let mut body = vec![Desugarer::assign(&flag, Desugarer::number(0))];
+                let rhs = self.condition(rhs, &mut body);
+                body.push(Desugarer::assign(&result, Desugarer::name(&rhs)));
+
+                statements.push(self.while_not_zero(&flag, body));
+                result
+            }
+            ConditionToken::Or { lhs, rhs } => {
+                let flag = self.condition(lhs, statements);
+                let otherwise = self.zero(flag, statements);
+                let result = self.fresh();
+                statements.push(Desugarer::assign(&result, Desugarer::number(1)));
+
+                let mut body = vec![Desugarer::assign(&otherwise, Desugarer::number(0))];
+                let rhs = self.condition(rhs, &mut body);
+                body.push(Desugarer::assign(&result, Desugarer::name(&rhs)));
+
+                statements.push(self.while_not_zero(&otherwise, body));
+                result
+            }
+        };
+    }
+
+    /// A variable holding the value of the assignable. Variables are used as they are.
+    fn variable(&mut self, assignable: &AssignableToken, statements: &mut Vec<Stackable>) -> NameToken {
+        return match assignable {
+            AssignableToken::Name { value } => value.clone(),
+            assignable => self.operand(assignable, statements)
+        };
+    }
+
+    /// Evaluates the assignable into a fresh variable.
+    fn operand(&mut self, assignable: &AssignableToken, statements: &mut Vec<Stackable>) -> NameToken {
+        let value = match assignable {
+            AssignableToken::Name { .. } | AssignableToken::Digit { .. } => assignable.clone(),
+            AssignableToken::MethodCall { value } => AssignableToken::MethodCall { value: self.method_call(value, statements) },
+            AssignableToken::Expression { value } => {
+                let lhs = self.operand(&value.lhs, statements);
+                let rhs = self.operand(&value.rhs, statements);
+
+                return self.arithmetic(value.operator, lhs, rhs, statements);
+            }
+        };
+
+        let temporary = self.fresh();
+        statements.push(Desugarer::assign(&temporary, value));
+
+        return temporary;
+    }
+
+    /// Arguments are passed as they are if they're all variables, otherwise each is evaluated in order.
+    fn method_call(&mut self, method_call_token: &MethodCallToken, statements: &mut Vec<Stackable>) -> MethodCallToken {
+        let mut method_call_token = method_call_token.clone();
+
+        if method_call_token.parameters.iter().all(|parameter| matches!(parameter, AssignableToken::Name { .. })) {
+            return method_call_token;
+        }
+
+        method_call_token.parameters = method_call_token.parameters.iter()
+            .map(|parameter| Desugarer::name(&self.operand(parameter, statements)))
+            .collect();
+
+        return method_call_token;
+    }
+
+    /// Applies the operator to two fresh variables, which may be changed.
+    fn arithmetic(&mut self, operator: ArithmeticOperator, lhs: NameToken, rhs: NameToken, statements: &mut Vec<Stackable>) -> NameToken {
+        return match operator {
+            ArithmeticOperator::Add | ArithmeticOperator::Sub => {
+                statements.push(self.while_not_zero(&rhs, vec![Desugarer::step(&lhs, operator), Desugarer::step(&rhs, ArithmeticOperator::Sub)]));
+                lhs
+            }
+            ArithmeticOperator::Mul => self.multiply(&lhs, rhs, statements),
+            ArithmeticOperator::Div => self.divide(lhs, rhs, statements).0,
+            ArithmeticOperator::Rem => self.divide(lhs, rhs, statements).1,
+            ArithmeticOperator::Pow => {
+                let result = self.fresh();
+                statements.push(Desugarer::assign(&result, Desugarer::number(1)));
+
+                let mut body = Vec::new();
+                let product = self.multiply(&lhs, result.clone(), &mut body);
+                body.push(Desugarer::assign(&result, Desugarer::name(&product)));
+                body.push(Desugarer::step(&rhs, ArithmeticOperator::Sub));
+
+                statements.push(self.while_not_zero(&rhs, body));
+                result
+            }
+        };
+    }
+
+    /// Adds `lhs` to a fresh variable `rhs` times.
+    fn multiply(&mut self, lhs: &NameToken, rhs: NameToken, statements: &mut Vec<Stackable>) -> NameToken {
+        let product = self.fresh();
+        let counter = self.fresh();
+        statements.push(Desugarer::assign(&product, Desugarer::number(0)));
+
+        let body = vec![
+            Desugarer::assign(&counter, Desugarer::name(lhs)),
+            self.while_not_zero(&counter, vec![Desugarer::step(&product, ArithmeticOperator::Add), Desugarer::step(&counter, ArithmeticOperator::Sub)]),
+            Desugarer::step(&rhs, ArithmeticOperator::Sub)
+        ];
+
+        statements.push(self.while_not_zero(&rhs, body));
+        return product;
+    }
+
+    /// Subtracts `rhs` from `lhs` as long as `lhs >= rhs`. Returns the quotient and the remainder.
+    fn divide(&mut self, lhs: NameToken, rhs: NameToken, statements: &mut Vec<Stackable>) -> (NameToken, NameToken) {
+        let quotient = self.fresh();
+        statements.push(Desugarer::assign(&quotient, Desugarer::number(0)));
+
+        let difference = self.difference(&rhs, &lhs, statements);
+        let fits = self.zero(difference, statements);
+
+        let mut body = Vec::new();
+        let counter = self.operand(&Desugarer::name(&rhs), &mut body);
+        body.push(self.while_not_zero(&counter, vec![Desugarer::step(&lhs, ArithmeticOperator::Sub), Desugarer::step(&counter, ArithmeticOperator::Sub)]));
+        body.push(Desugarer::step(&quotient, ArithmeticOperator::Add));
+
+        let difference = self.difference(&rhs, &lhs, &mut body);
+        let next = self.zero(difference, &mut body);
+        body.push(Desugarer::assign(&fits, Desugarer::name(&next)));
+
+        statements.push(self.while_not_zero(&fits, body));
+        return (quotient, lhs);
+    }
+
+    /// `max(0, lhs - rhs)` in a fresh variable, `lhs` and `rhs` are unchanged.
+    fn difference(&mut self, lhs: &NameToken, rhs: &NameToken, statements: &mut Vec<Stackable>) -> NameToken {
+        let difference = self.operand(&Desugarer::name(lhs), statements);
+        let counter = self.operand(&Desugarer::name(rhs), statements);
+
+        return self.arithmetic(ArithmeticOperator::Sub, difference, counter, statements);
+    }
+
+    /// 1 if the fresh variable isn't zero, 0 otherwise.
+    fn not_zero(&mut self, value: NameToken, statements: &mut Vec<Stackable>) -> NameToken {
+        return self.flag(value, 0, statements);
+    }
+
+    /// 1 if the fresh variable is zero, 0 otherwise.
+    fn zero(&mut self, value: NameToken, statements: &mut Vec<Stackable>) -> NameToken {
+        return self.flag(value, 1, statements);
+    }
+
+    fn flag(&mut self, value: NameToken, if_zero: u32, statements: &mut Vec<Stackable>) -> NameToken {
+        let flag = self.fresh();
+        statements.push(Desugarer::assign(&flag, Desugarer::number(if_zero)));
+
+        let body = vec![Desugarer::assign(&flag, Desugarer::number(1 - if_zero)), Desugarer::assign(&value, Desugarer::number(0))];
+        statements.push(self.while_not_zero(&value, body));
+
+        return flag;
+    }
+
+    fn fresh(&mut self) -> NameToken {
+        let mut index = self.temporaries.len() + 1;
+
+        while self.names.contains(&format!("t_{}", index)) {
+            index += 1;
+        }
+
+        let name = format!("t_{}", index);
+        self.names.insert(name.clone());
+        self.temporaries.insert(name.clone());
+
+        return NameToken::new(&name);
+    }
+
+    fn while_not_zero(&self, name: &NameToken, stack: Vec<Stackable>) -> Stackable {
+        let mut while_token = WhileToken::new(WhileHeaderToken::against_zero(name.clone()), self.header.clone(), SourceLocation::default());
+        while_token.scope = Some(self.scope(stack));
+
+        return Stackable::WhileToken { value: while_token };
+    }
+
+    fn scope(&self, stack: Vec<Stackable>) -> InnerBodyScope {
+        let mut scope = InnerBodyScope::new(self.header.clone());
+        scope.stack = stack;
+
+        return scope;
+    }
+
+    /// The variable of a classic condition `x != 0`.
+    fn against_zero(condition: &ConditionToken) -> Option<NameToken> {
+        return match condition {
+            ConditionToken::Comparison { lhs: AssignableToken::Name { value }, comparator: Comparator::NotEqual, rhs: AssignableToken::Digit { value: zero } }
+                if zero.evaluate().is_zero() => Some(value.clone()),
+            _ => None
+        };
+    }
+
+    fn is_one(assignable: &AssignableToken) -> bool {
+        return matches!(assignable, AssignableToken::Digit { value } if value.evaluate() == Natural::from(1u32));
+    }
+
+    fn assign(name: &NameToken, assignment: AssignableToken) -> Stackable {
+        return Stackable::VariableToken { value: VariableToken::new(name.clone(), assignment, SourceLocation::default()) };
+    }
+
+    fn step(name: &NameToken, operator: ArithmeticOperator) -> Stackable {
+        let operator_token = OperatorToken {
+            name: name.clone(),
+            operator,
+            rhs_operand: Desugarer::number(1),
+            location: SourceLocation::default()
+        };
+
+        return Stackable::OperatorToken { value: operator_token };
+    }
+
+    fn name(name: &NameToken) -> AssignableToken {
+        return AssignableToken::Name { value: name.clone() };
+    }
+
+    fn number(value: u32) -> AssignableToken {
+        return AssignableToken::Digit { value: DigitToken::new(Natural::from(value)) };
+    }
+}
@@ -0,0 +1,55 @@
+use std::collections::{BTreeSet, HashMap};
+use crate::interpreter::desugar::Desugarer;
+use crate::interpreter::errors::{ErrorKind, WhileError};
+use crate::interpreter::executor_states::RunTime;
+use crate::interpreter::lexer::Lexer;
+use crate::interpreter::lexer::scopes::TopLevelScope;
+use crate::interpreter::models::{CodeLine, Value};
+use crate::interpreter::utils::logging::{Logger, SourceViewElement};
+use crate::interpreter::utils::output::BufferedOutput;
+
+/// Desugars the program, parses the printed core program again and runs both with natural numbers.
+/// Returns the source of the core program if both end with the same global variables, apart from
+/// the variables of the desugaring, and wrote the same output. An error of the original program is
+/// returned as it is.
+pub fn check_equivalence(scope: &TopLevelScope) -> Result<Vec<String>, WhileError> {
+    let mut desugarer = Desugarer::new(scope);
+    let source = desugarer.desugar(scope).to_source();
+
+    let code_lines = source.iter().enumerate().map(|(i, line)| CodeLine::new(line, (i + 1) as u32)).collect::<Vec<CodeLine>>();
+
+    let core = Lexer::new(Logger::NoLogger).tokenize(code_lines)
+        .map_err(|errors| not_equivalent(format!("The desugared program doesn't parse, {}", errors[0])))?;
+
+    let (globals, output) = run(scope.clone())?;
+    let (core_globals, core_output) = run(core).map_err(|error| not_equivalent(format!("The desugared program fails, {}", error)))?;
+
+    let names = globals.keys().chain(core_globals.keys().filter(|name| !desugarer.is_temporary(name))).collect::<BTreeSet<&String>>();
+
+    for name in names {
+        let value = globals.get(name).map_or("undefined".to_string(), Value::to_string);
+        let core_value = core_globals.get(name).map_or("undefined".to_string(), Value::to_string);
+
+        if value != core_value {
+            return Err(not_equivalent(format!("{} is {} after the program, but {} after the desugared program", name, value, core_value)));
+        }
+    }
+
+    if output != core_output {
+        return Err(not_equivalent(format!("The program writes {:?}, but the desugared program writes {:?}", output, core_output)));
+    }
+
+    return Ok(source);
+}
+
+fn run(scope: TopLevelScope) -> Result<(HashMap<String, Value>, Vec<String>), WhileError> {
+    let output = BufferedOutput::new();
+    let mut run_time = RunTime::with_output(scope, Logger::NoLogger, Box::new(output.clone()));
+    run_time.run()?;
+
+    return Ok((run_time.context().environment.globals().clone(), output.lines()));
+}
+
+fn not_equivalent(message: String) -> WhileError {
+    return WhileError::runtime(ErrorKind::NotEquivalent, message);
+}
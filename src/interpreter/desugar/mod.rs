@@ -0,0 +1,5 @@
+mod desugarer;
+mod equivalence;
+
+pub use desugarer::Desugarer;
+pub use equivalence::check_equivalence;
@@ -0,0 +1,4 @@
+mod while_error;
+
+pub use while_error::ErrorKind;
+pub use while_error::WhileError;
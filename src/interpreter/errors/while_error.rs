@@ -0,0 +1,148 @@
+use std::fmt::{Display, Formatter};
+use crate::interpreter::models::{CodeLine, SourceLocation};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    // Syntax errors, reported by the lexer
+    ExpectedSemicolon,
+    ExpectedColon,
+    ExpectedName,
+    ExpectedAssignable,
+    UnexpectedToken,
+    UnbalancedParentheses,
+    EmptyParameter,
+    InvalidReturn,
+    InvalidLoopControl,
+    MissingEscapeToken,
+    MissingReturn,
+    EmptyMethod,
+    WhileInLoopProgram,
+    InvalidMacro,
+    MacroExpansion,
+
+    // Semantic errors, reported before the program runs
+    DuplicateMethod,
+    WrongArgumentCount,
+    Recursion,
+
+    // Runtime errors, reported while executing
+    VariableNotFound,
+    MethodNotFound,
+    Underflow,
+    Overflow,
+    DivisionByZero,
+
+    // Reported by the check of a desugared program
+    NotEquivalent,
+
+    // Reported when the source can't be read
+    Io,
+
+    // Reported by fmt for source it can't print without losing parts of it
+    Unformattable,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ErrorKind::ExpectedSemicolon => "expected semicolon",
+            ErrorKind::ExpectedColon => "expected colon",
+            ErrorKind::ExpectedName => "expected name",
+            ErrorKind::ExpectedAssignable => "expected assignable",
+            ErrorKind::UnexpectedToken => "unexpected token",
+            ErrorKind::UnbalancedParentheses => "unbalanced parentheses",
+            ErrorKind::EmptyParameter => "empty parameter",
+            ErrorKind::InvalidReturn => "invalid return",
+            ErrorKind::InvalidLoopControl => "invalid loop control",
+            ErrorKind::MissingEscapeToken => "missing escape token",
+            ErrorKind::MissingReturn => "missing return",
+            ErrorKind::EmptyMethod => "empty method",
+            ErrorKind::WhileInLoopProgram => "while in loop program",
+            ErrorKind::InvalidMacro => "invalid macro",
+            ErrorKind::MacroExpansion => "macro expansion",
+            ErrorKind::DuplicateMethod => "duplicate method",
+            ErrorKind::WrongArgumentCount => "wrong argument count",
+            ErrorKind::Recursion => "recursion",
+            ErrorKind::VariableNotFound => "variable not found",
+            ErrorKind::MethodNotFound => "method not found",
+            ErrorKind::Underflow => "underflow",
+            ErrorKind::Overflow => "overflow",
+            ErrorKind::DivisionByZero => "division by zero",
+            ErrorKind::NotEquivalent => "not equivalent",
+            ErrorKind::Io => "io",
+            ErrorKind::Unformattable => "unformattable",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// A single diagnostic. A line of 0 means the location is unknown, e.g. for a file that can't be read.
+/// Errors found by the lexer keep the code line they were raised for, runtime errors have the location
+/// of the failing statement and get their code line from `with_source`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WhileError {
+    pub kind: ErrorKind,
+    pub message: String,
+    pub line: u32,
+    pub column: u32,
+    pub code_line: Option<CodeLine>,
+}
+
+impl Display for WhileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.line == 0 {
+            return write!(f, "error[{}]: {}", self.kind, self.message);
+        }
+
+        write!(f, "error[{}] at line {}, column {}: {}", self.kind, self.line, self.column, self.message)?;
+
+        if let Some(code_line) = &self.code_line {
+            write!(f, "\n{:>5} | {}", self.line, code_line.line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for WhileError { }
+
+impl WhileError {
+    pub fn new(kind: ErrorKind, message: String, line: u32, column: u32) -> Self {
+        WhileError {
+            kind,
+            message,
+            line,
+            column,
+            code_line: None
+        }
+    }
+
+    pub fn at(kind: ErrorKind, message: String, code_line: &CodeLine) -> Self {
+        WhileError::at_column(kind, message, code_line, code_line.column)
+    }
+
+    pub fn at_column(kind: ErrorKind, message: String, code_line: &CodeLine, column: u32) -> Self {
+        WhileError {
+            code_line: Some(code_line.clone()),
+            ..WhileError::new(kind, message, code_line.line_number, column)
+        }
+    }
+
+    pub fn runtime(kind: ErrorKind, message: String) -> Self {
+        WhileError::new(kind, message, 0, 0)
+    }
+
+    pub fn runtime_at(kind: ErrorKind, message: String, location: SourceLocation) -> Self {
+        WhileError::new(kind, message, location.line, location.column)
+    }
+
+    /// Adds the code line of the location, if the error doesn't have one yet.
+    pub fn with_source(mut self, code_lines: &[CodeLine]) -> Self {
+        if self.code_line.is_none() {
+            self.code_line = code_lines.iter().find(|code_line| code_line.line_number == self.line).cloned();
+        }
+
+        return self;
+    }
+}
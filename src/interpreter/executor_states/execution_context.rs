@@ -0,0 +1,182 @@
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use crate::interpreter::errors::{ErrorKind, WhileError};
+use crate::interpreter::lexer::expressions::ArithmeticOperator;
+use crate::interpreter::lexer::methods::MethodToken;
+use crate::interpreter::lexer::operators::OperatorToken;
+use crate::interpreter::lexer::variables::VariableToken;
+use crate::interpreter::executor_states::{RunTimeOptions, Tracer, UnderflowMode};
+use crate::interpreter::models::{Environment, MethodsList, Natural, SourceLocation, Value};
+use crate::interpreter::utils::output::{OutputWriter, StdOutput};
+
+/// Owns everything a running program can observe: the variables, the method table and the output.
+/// Every evaluation receives the context explicitly, so independent programs never share state.
+pub struct ExecutionContext {
+    pub environment: Environment,
+    pub methods: MethodsList,
+    pub options: RunTimeOptions,
+    /// The statement that runs, runtime errors are reported at its location.
+    pub location: SourceLocation,
+    output: Box<dyn OutputWriter>,
+    tracer: Option<Tracer>,
+}
+
+impl Default for ExecutionContext {
+    fn default() -> Self {
+        ExecutionContext::new(&[])
+    }
+}
+
+impl Display for ExecutionContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.environment)
+    }
+}
+
+impl ExecutionContext {
+    pub fn new(methods: &[MethodToken]) -> Self {
+        return ExecutionContext::with_output(methods, Box::new(StdOutput));
+    }
+
+    pub fn with_output(methods: &[MethodToken], output: Box<dyn OutputWriter>) -> Self {
+        let mut methods_list = MethodsList::new();
+
+        for method in methods {
+            methods_list.insert(method.header_token.name.value.clone(), method.clone());
+        }
+
+        ExecutionContext {
+            environment: Environment::new(),
+            methods: methods_list,
+            options: RunTimeOptions::default(),
+            location: SourceLocation::default(),
+            output,
+            tracer: None,
+        }
+    }
+
+    pub fn get_method_token(&self, method_name: &str) -> Option<Arc<MethodToken>> {
+        return self.methods.get(method_name);
+    }
+
+    pub fn write_line(&mut self, line: &str) {
+        self.output.write_line(line);
+    }
+
+    /// Writes a step log of the run to `output`.
+    pub fn trace_to(&mut self, output: Box<dyn OutputWriter>) {
+        self.tracer = Some(Tracer::new(output));
+    }
+
+    /// Adds a line to the step log, the line is only built if the run is traced.
+    pub fn trace(&mut self, line: impl FnOnce(&ExecutionContext) -> String) {
+        if self.tracer.is_none() {
+            return;
+        }
+
+        let line = line(self);
+
+        if let Some(tracer) = &mut self.tracer {
+            tracer.write_line(&line);
+        }
+    }
+
+    /// Runs a body or a method call, its steps are indented in the step log.
+    pub fn traced_block<T>(&mut self, block: impl FnOnce(&mut ExecutionContext) -> T) -> T {
+        if let Some(tracer) = &mut self.tracer {
+            tracer.enter();
+        }
+
+        let result = block(self);
+
+        if let Some(tracer) = &mut self.tracer {
+            tracer.leave();
+        }
+
+        return result;
+    }
+
+    /// Opens the frame of a method call with its parameters.
+    pub fn push_frame(&mut self, parameters: Vec<(String, Value)>) {
+        self.environment.push_frame();
+
+        for (name, value) in parameters {
+            self.environment.assign(&name, value);
+        }
+    }
+
+    /// Closes the frame of a method call.
+    pub fn pop_frame(&mut self) {
+        self.environment.pop_frame();
+    }
+
+    /// Evaluates the right-hand side once and stores the resulting value.
+    pub fn assign(&mut self, token: &VariableToken) -> Result<(), WhileError> {
+        let value = token.assignment.evaluate(self)?;
+        self.environment.assign(&token.name.value, value);
+
+        Ok(())
+    }
+
+    pub fn update(&mut self, operator_token: &OperatorToken) -> Result<(), WhileError> {
+        let rhs = operator_token.rhs_operand.evaluate(self)?;
+
+        let variable = match self.environment.get_for_update(&operator_token.name.value) {
+            Some(variable) => variable,
+            None => return Err(WhileError::runtime_at(ErrorKind::VariableNotFound, format!("You can't operate on a non existent variable: {}", operator_token.name.value), operator_token.location))
+        };
+
+        let value = ExecutionContext::apply(&self.options, operator_token.operator, variable, &rhs)
+            .map_err(|kind| ExecutionContext::arithmetic_error(kind, &self.options, &format!("{} {}= {}", operator_token.name.value, operator_token.operator, rhs), &format!(", {} is {}", operator_token.name.value, variable), operator_token.location))?;
+
+        *variable = value;
+
+        Ok(())
+    }
+
+    /// Applies an operator in the configured numeric mode.
+    pub fn calculate(&self, operator: ArithmeticOperator, lhs: &Value, rhs: &Value) -> Result<Value, WhileError> {
+        return ExecutionContext::apply(&self.options, operator, lhs, rhs)
+            .map_err(|kind| ExecutionContext::arithmetic_error(kind, &self.options, &format!("{} {} {}", lhs, operator, rhs), "", self.location));
+    }
+
+    fn apply(options: &RunTimeOptions, operator: ArithmeticOperator, lhs: &Value, rhs: &Value) -> Result<Value, ErrorKind> {
+        let result = match operator {
+            ArithmeticOperator::Add => options.numeric.add(lhs, rhs),
+            ArithmeticOperator::Sub => options.numeric.sub(lhs, rhs),
+            ArithmeticOperator::Mul => options.numeric.mul(lhs, rhs),
+            ArithmeticOperator::Div => options.numeric.div(lhs, rhs),
+            ArithmeticOperator::Rem => options.numeric.rem(lhs, rhs),
+            ArithmeticOperator::Pow => options.numeric.pow(lhs, rhs)
+        };
+
+        return match result {
+            Err(ErrorKind::Underflow) if options.underflow == UnderflowMode::Saturate => Ok(Value::default()),
+            result => result
+        };
+    }
+
+    /// `operation` describes what was calculated, `detail` is appended to the message.
+    fn arithmetic_error(kind: ErrorKind, options: &RunTimeOptions, operation: &str, detail: &str, location: SourceLocation) -> WhileError {
+        let message = match kind {
+            ErrorKind::Underflow => format!("{} goes below zero{}", operation, detail),
+            ErrorKind::DivisionByZero => format!("{} divides by zero{}", operation, detail),
+            _ => format!("{} doesn't fit into {}{}", operation, options.numeric, detail)
+        };
+
+        return WhileError::runtime_at(kind, message, location);
+    }
+
+    /// Converts a number literal into a value of the configured numeric mode.
+    pub fn literal(&self, literal: &Natural) -> Result<Value, WhileError> {
+        return self.options.numeric.literal(literal)
+            .map_err(|kind| WhileError::runtime_at(kind, format!("The literal {} doesn't fit into {}", literal, self.options.numeric), self.location));
+    }
+
+    pub fn get_value_from_current_name(&self, variable_name: &str) -> Result<Value, WhileError> {
+        return match self.environment.get(variable_name) {
+            Some(value) => Ok(value),
+            None => Err(WhileError::runtime_at(ErrorKind::VariableNotFound, format!("Variable {} not found.", variable_name), self.location))
+        };
+    }
+}
@@ -1,3 +1,11 @@
 mod runtime;
+mod execution_context;
+mod runtime_options;
+mod numeric_mode;
+mod tracer;
 
-pub use runtime::RunTime;
\ No newline at end of file
+pub use runtime::RunTime;
+pub use execution_context::ExecutionContext;
+pub use runtime_options::{RunTimeOptions, UnderflowMode};
+pub use numeric_mode::{IntegerType, NumericMode, OverflowMode};
+pub use tracer::Tracer;
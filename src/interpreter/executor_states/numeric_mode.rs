@@ -0,0 +1,301 @@
+use std::fmt::{Display, Formatter};
+use crate::interpreter::errors::ErrorKind;
+use crate::interpreter::models::{Natural, Value};
+
+/// What happens when a result doesn't fit into a fixed width integer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OverflowMode {
+    /// The result is taken modulo 2^width, like machine arithmetic.
+    Wrap,
+    /// The result is clamped to the smallest or largest value of the type.
+    Saturate,
+    /// The operation is reported as a runtime error.
+    Trap,
+}
+
+impl Display for OverflowMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OverflowMode::Wrap => write!(f, "wrap"),
+            OverflowMode::Saturate => write!(f, "saturate"),
+            OverflowMode::Trap => write!(f, "trap")
+        }
+    }
+}
+
+impl OverflowMode {
+    pub fn analyse(mode: &str) -> Option<OverflowMode> {
+        return match mode {
+            "wrap" => Some(OverflowMode::Wrap),
+            "saturate" => Some(OverflowMode::Saturate),
+            "trap" => Some(OverflowMode::Trap),
+            _ => None
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IntegerType {
+    U8,
+    U16,
+    U32,
+    U64,
+    I64,
+}
+
+impl Display for IntegerType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntegerType::U8 => write!(f, "u8"),
+            IntegerType::U16 => write!(f, "u16"),
+            IntegerType::U32 => write!(f, "u32"),
+            IntegerType::U64 => write!(f, "u64"),
+            IntegerType::I64 => write!(f, "i64")
+        }
+    }
+}
+
+impl IntegerType {
+    pub fn analyse(name: &str) -> Option<IntegerType> {
+        return match name {
+            "u8" => Some(IntegerType::U8),
+            "u16" => Some(IntegerType::U16),
+            "u32" => Some(IntegerType::U32),
+            "u64" => Some(IntegerType::U64),
+            "i64" => Some(IntegerType::I64),
+            _ => None
+        }
+    }
+
+    pub fn min(&self) -> i128 {
+        return match self {
+            IntegerType::I64 => i64::MIN as i128,
+            _ => 0
+        };
+    }
+
+    pub fn max(&self) -> i128 {
+        return match self {
+            IntegerType::U8 => u8::MAX as i128,
+            IntegerType::U16 => u16::MAX as i128,
+            IntegerType::U32 => u32::MAX as i128,
+            IntegerType::U64 => u64::MAX as i128,
+            IntegerType::I64 => i64::MAX as i128
+        };
+    }
+
+    /// Reduces a value modulo 2^width into the range of the type.
+    fn wrap(&self, value: i128) -> i128 {
+        return match self {
+            IntegerType::U8 => value as u8 as i128,
+            IntegerType::U16 => value as u16 as i128,
+            IntegerType::U32 => value as u32 as i128,
+            IntegerType::U64 => value as u64 as i128,
+            IntegerType::I64 => value as i64 as i128
+        };
+    }
+}
+
+/// The arithmetic a program runs with. Every literal and every result passes through the mode,
+/// so a variable always holds a value of the mode's type.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum NumericMode {
+    /// Unbounded natural numbers, the classic While semantics.
+    #[default]
+    Natural,
+    /// Machine integers of a fixed width.
+    Fixed(IntegerType, OverflowMode),
+}
+
+impl Display for NumericMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NumericMode::Natural => write!(f, "natural"),
+            NumericMode::Fixed(integer_type, overflow) => write!(f, "{}-{}", integer_type, overflow)
+        }
+    }
+}
+
+impl NumericMode {
+    /// Accepts `natural` or a type and an overflow mode like `u8-wrap`, `u32-saturate` or `i64-trap`.
+    pub fn analyse(mode: &str) -> Option<NumericMode> {
+        if mode == "natural" {
+            return Some(NumericMode::Natural);
+        }
+
+        let (integer_type, overflow) = mode.split_once('-')?;
+
+        return Some(NumericMode::Fixed(IntegerType::analyse(integer_type)?, OverflowMode::analyse(overflow)?));
+    }
+
+    /// Converts a literal into a value of the mode. Literals don't have a width, so they may overflow as well.
+    pub fn literal(&self, literal: &Natural) -> Result<Value, ErrorKind> {
+        return match self {
+            NumericMode::Natural => Ok(Value::from(literal.clone())),
+            NumericMode::Fixed(integer_type, overflow) => match literal.to_u128().and_then(|value| i128::try_from(value).ok()) {
+                Some(value) => NumericMode::fit(value, *integer_type, *overflow),
+                None => match overflow {
+                    OverflowMode::Wrap => Ok(Value::integer(integer_type.wrap(literal.low_u64() as i128))),
+                    OverflowMode::Saturate => Ok(Value::integer(integer_type.max())),
+                    OverflowMode::Trap => Err(ErrorKind::Overflow)
+                }
+            }
+        };
+    }
+
+    pub fn add(&self, lhs: &Value, rhs: &Value) -> Result<Value, ErrorKind> {
+        return match self {
+            NumericMode::Natural => Ok(Value::from(lhs.magnitude().add(rhs.magnitude()))),
+            NumericMode::Fixed(integer_type, overflow) => {
+                let (lhs, rhs) = NumericMode::operands(lhs, rhs)?;
+                NumericMode::fit(lhs + rhs, *integer_type, *overflow)
+            }
+        };
+    }
+
+    /// Natural numbers can't go below zero, which is reported as `Underflow` for the caller to handle.
+    pub fn sub(&self, lhs: &Value, rhs: &Value) -> Result<Value, ErrorKind> {
+        return match self {
+            NumericMode::Natural => match lhs.magnitude().checked_sub(rhs.magnitude()) {
+                Some(value) => Ok(Value::from(value)),
+                None => Err(ErrorKind::Underflow)
+            },
+            NumericMode::Fixed(integer_type, overflow) => {
+                let (lhs, rhs) = NumericMode::operands(lhs, rhs)?;
+                NumericMode::fit(lhs - rhs, *integer_type, *overflow)
+            }
+        };
+    }
+
+    /// A product that doesn't even fit into an `i128` overflows in every fixed width mode.
+    pub fn mul(&self, lhs: &Value, rhs: &Value) -> Result<Value, ErrorKind> {
+        return match self {
+            NumericMode::Natural => Ok(Value::from(lhs.magnitude().mul(rhs.magnitude()))),
+            NumericMode::Fixed(integer_type, overflow) => {
+                let (lhs, rhs) = NumericMode::operands(lhs, rhs)?;
+
+                match lhs.checked_mul(rhs) {
+                    Some(value) => NumericMode::fit(value, *integer_type, *overflow),
+                    None => match overflow {
+                        // the lowest bits of the wrapped i128 product are still correct
+                        OverflowMode::Wrap => Ok(Value::integer(integer_type.wrap(lhs.wrapping_mul(rhs)))),
+                        OverflowMode::Saturate if (lhs < 0) != (rhs < 0) => Ok(Value::integer(integer_type.min())),
+                        OverflowMode::Saturate => Ok(Value::integer(integer_type.max())),
+                        OverflowMode::Trap => Err(ErrorKind::Overflow)
+                    }
+                }
+            }
+        };
+    }
+
+    /// Integer division, which rounds towards zero. Dividing by zero is an error in every mode.
+    pub fn div(&self, lhs: &Value, rhs: &Value) -> Result<Value, ErrorKind> {
+        return match self {
+            NumericMode::Natural => match lhs.magnitude().div_rem(rhs.magnitude()) {
+                Some((quotient, _)) => Ok(Value::from(quotient)),
+                None => Err(ErrorKind::DivisionByZero)
+            },
+            NumericMode::Fixed(integer_type, overflow) => {
+                let (lhs, rhs) = NumericMode::operands(lhs, rhs)?;
+
+                match lhs.checked_div(rhs) {
+                    Some(value) => NumericMode::fit(value, *integer_type, *overflow),
+                    None => Err(ErrorKind::DivisionByZero)
+                }
+            }
+        };
+    }
+
+    /// The remainder of `div`, which has the sign of `lhs`.
+    pub fn rem(&self, lhs: &Value, rhs: &Value) -> Result<Value, ErrorKind> {
+        return match self {
+            NumericMode::Natural => match lhs.magnitude().div_rem(rhs.magnitude()) {
+                Some((_, remainder)) => Ok(Value::from(remainder)),
+                None => Err(ErrorKind::DivisionByZero)
+            },
+            NumericMode::Fixed(integer_type, overflow) => {
+                let (lhs, rhs) = NumericMode::operands(lhs, rhs)?;
+
+                match lhs.checked_rem(rhs) {
+                    Some(value) => NumericMode::fit(value, *integer_type, *overflow),
+                    None => Err(ErrorKind::DivisionByZero)
+                }
+            }
+        };
+    }
+
+    /// A negative exponent is `1 / x^-y` like in `div`, so it is zero unless `x` is 1 or -1, and dividing by zero if `x` is 0.
+    pub fn pow(&self, lhs: &Value, rhs: &Value) -> Result<Value, ErrorKind> {
+        return match self {
+            NumericMode::Natural => Ok(Value::from(lhs.magnitude().pow(rhs.magnitude()))),
+            NumericMode::Fixed(integer_type, overflow) => {
+                let (base, exponent) = NumericMode::operands(lhs, rhs)?;
+
+                if exponent < 0 {
+                    return match base {
+                        0 => Err(ErrorKind::DivisionByZero),
+                        1 => Ok(Value::integer(1)),
+                        -1 => Ok(Value::integer(if exponent % 2 == 0 { 1 } else { -1 })),
+                        _ => Ok(Value::integer(0))
+                    };
+                }
+
+                match NumericMode::checked_pow(base, exponent) {
+                    Some(value) => NumericMode::fit(value, *integer_type, *overflow),
+                    None => match overflow {
+                        OverflowMode::Wrap => Ok(Value::integer(integer_type.wrap(NumericMode::wrapping_pow(base, exponent)))),
+                        OverflowMode::Saturate if base < 0 && exponent % 2 == 1 => Ok(Value::integer(integer_type.min())),
+                        OverflowMode::Saturate => Ok(Value::integer(integer_type.max())),
+                        OverflowMode::Trap => Err(ErrorKind::Overflow)
+                    }
+                }
+            }
+        };
+    }
+
+    /// The exact power, `None` if it doesn't fit into an `i128`.
+    fn checked_pow(base: i128, exponent: i128) -> Option<i128> {
+        return match (base, exponent) {
+            (_, 0) => Some(1),
+            (-1, _) if exponent % 2 == 0 => Some(1),
+            (-1..=1, _) => Some(base),
+            // |base| >= 2, so every exponent above 127 overflows
+            _ => base.checked_pow(u32::try_from(exponent).ok().filter(|exponent| *exponent < 128)?)
+        };
+    }
+
+    /// The power modulo 2^128, which keeps the lowest bits of the exact power.
+    fn wrapping_pow(mut base: i128, mut exponent: i128) -> i128 {
+        let mut result: i128 = 1;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.wrapping_mul(base);
+            }
+
+            base = base.wrapping_mul(base);
+            exponent >>= 1;
+        }
+
+        return result;
+    }
+
+    fn operands(lhs: &Value, rhs: &Value) -> Result<(i128, i128), ErrorKind> {
+        return match (lhs.to_i128(), rhs.to_i128()) {
+            (Some(lhs), Some(rhs)) => Ok((lhs, rhs)),
+            _ => Err(ErrorKind::Overflow)
+        };
+    }
+
+    fn fit(value: i128, integer_type: IntegerType, overflow: OverflowMode) -> Result<Value, ErrorKind> {
+        if value >= integer_type.min() && value <= integer_type.max() {
+            return Ok(Value::integer(value));
+        }
+
+        return match overflow {
+            OverflowMode::Wrap => Ok(Value::integer(integer_type.wrap(value))),
+            OverflowMode::Saturate => Ok(Value::integer(value.clamp(integer_type.min(), integer_type.max()))),
+            OverflowMode::Trap => Err(ErrorKind::Overflow)
+        };
+    }
+}
@@ -0,0 +1,28 @@
+use crate::interpreter::executor_states::NumericMode;
+
+/// What happens when a subtraction of natural numbers would go below zero.
+/// Fixed width modes handle this with their own `OverflowMode`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum UnderflowMode {
+    /// `x - y` is `max(0, x - y)`, the classic While semantics.
+    #[default]
+    Saturate,
+    /// The subtraction is reported as a runtime error.
+    Error,
+}
+
+impl UnderflowMode {
+    pub fn analyse(mode: &str) -> Option<UnderflowMode> {
+        return match mode {
+            "saturate" => Some(UnderflowMode::Saturate),
+            "error" => Some(UnderflowMode::Error),
+            _ => None
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RunTimeOptions {
+    pub underflow: UnderflowMode,
+    pub numeric: NumericMode,
+}
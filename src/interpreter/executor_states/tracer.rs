@@ -0,0 +1,29 @@
+use crate::interpreter::utils::output::OutputWriter;
+
+/// Writes the step log of a traced run. Every executed statement, loop condition and method call
+/// gets a line, indented by the blocks and method calls it's nested in.
+pub struct Tracer {
+    output: Box<dyn OutputWriter>,
+    depth: usize
+}
+
+impl Tracer {
+    pub fn new(output: Box<dyn OutputWriter>) -> Self {
+        Tracer {
+            output,
+            depth: 0
+        }
+    }
+
+    pub fn write_line(&mut self, line: &str) {
+        self.output.write_line(&format!("{}{}", "    ".repeat(self.depth), line));
+    }
+
+    pub fn enter(&mut self) {
+        self.depth += 1;
+    }
+
+    pub fn leave(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+    }
+}
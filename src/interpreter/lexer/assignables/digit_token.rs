@@ -1,10 +1,10 @@
 use std::fmt::{Display, Formatter};
-use regex::Regex;
-use crate::interpreter::utils::logging::TreeViewElement;
+use crate::interpreter::models::Natural;
+use crate::interpreter::utils::logging::{SourceViewElement, TreeViewElement};
 
 #[derive(Clone, PartialEq)]
 pub struct DigitToken {
-    value: u32
+    value: Natural
 }
 
 impl Display for DigitToken {
@@ -20,24 +20,23 @@ impl TreeViewElement for DigitToken {
 }
 
 impl DigitToken {
-    pub fn new(value: u32) -> Self {
+    pub fn new(value: Natural) -> Self {
         DigitToken {
             value
         }
     }
 
-    pub fn evaluate(&self) -> u32 {
-        return self.value;
+    pub fn evaluate(&self) -> Natural {
+        return self.value.clone();
     }
 
     pub fn parse(assignment: &str) -> Option<DigitToken> {
-        let regex = Regex::new("^[0-9]+$").unwrap();
-        if !regex.is_match(assignment) {
-            return None;
-        }
+        return Natural::parse(assignment).map(DigitToken::new);
+    }
+}
 
-        return Some(
-            DigitToken::new(assignment.parse::<u32>().unwrap())
-        );
+impl SourceViewElement for DigitToken {
+    fn to_source(&self) -> Vec<String> {
+        return vec![self.value.to_string()];
     }
-}
\ No newline at end of file
+}
@@ -1,8 +1,8 @@
 use std::fmt::{Display, Formatter};
-use regex::Regex;
-use crate::interpreter::constants::KEYWORDS;
-use crate::interpreter::executor_states::RunTime;
-use crate::interpreter::utils::logging::TreeViewElement;
+use crate::interpreter::errors::WhileError;
+use crate::interpreter::executor_states::ExecutionContext;
+use crate::interpreter::models::Value;
+use crate::interpreter::utils::logging::{SourceViewElement, TreeViewElement};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct NameToken {
@@ -28,20 +28,13 @@ impl NameToken {
         }
     }
 
-    pub fn evaluate(&self) -> u32 {
-        return RunTime::get_value_from_current_name(&self.value);
+    pub fn evaluate(&self, context: &ExecutionContext) -> Result<Value, WhileError> {
+        return context.get_value_from_current_name(&self.value);
     }
+}
 
-    pub fn parse(line: &str) -> Option<Self> {
-        if KEYWORDS.iter().any(|keyword| line.contains(keyword)) {
-            return None;
-        }
-
-        let regex = Regex::new("^[a-zA-Z_$][a-zA-Z_$0-9$]*$").unwrap();
-        if !regex.is_match(line) {
-            return None;
-        }
-
-        return Some(NameToken::new(line));
+impl SourceViewElement for NameToken {
+    fn to_source(&self) -> Vec<String> {
+        return vec![self.value.clone()];
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,39 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Comparator {
+    NotEqual,
+    Equal,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual
+}
+
+impl Display for Comparator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Comparator::NotEqual => write!(f, "!="),
+            Comparator::Equal => write!(f, "=="),
+            Comparator::Less => write!(f, "<"),
+            Comparator::LessEqual => write!(f, "<="),
+            Comparator::Greater => write!(f, ">"),
+            Comparator::GreaterEqual => write!(f, ">=")
+        }
+    }
+}
+
+impl Comparator {
+    /// Whether two values ordered like `ordering` satisfy the comparison.
+    pub fn matches(&self, ordering: Ordering) -> bool {
+        return match self {
+            Comparator::NotEqual => ordering != Ordering::Equal,
+            Comparator::Equal => ordering == Ordering::Equal,
+            Comparator::Less => ordering == Ordering::Less,
+            Comparator::LessEqual => ordering != Ordering::Greater,
+            Comparator::Greater => ordering == Ordering::Greater,
+            Comparator::GreaterEqual => ordering != Ordering::Less
+        };
+    }
+}
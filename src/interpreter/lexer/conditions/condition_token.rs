@@ -0,0 +1,123 @@
+use std::fmt::{Display, Formatter};
+use crate::interpreter::errors::WhileError;
+use crate::interpreter::executor_states::ExecutionContext;
+use crate::interpreter::lexer::assignables::{DigitToken, NameToken};
+use crate::interpreter::lexer::conditions::Comparator;
+use crate::interpreter::lexer::models::AssignableToken;
+use crate::interpreter::models::Natural;
+use crate::interpreter::utils::logging::{SourceViewElement, TreeViewElement};
+
+/// The condition of a block. Classic While only knows `x != 0`, the extended dialect
+/// compares any two assignables and combines comparisons with `not`, `and` and `or`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConditionToken {
+    Comparison { lhs: AssignableToken, comparator: Comparator, rhs: AssignableToken },
+    Not { value: Box<ConditionToken> },
+    And { lhs: Box<ConditionToken>, rhs: Box<ConditionToken> },
+    Or { lhs: Box<ConditionToken>, rhs: Box<ConditionToken> }
+}
+
+impl Display for ConditionToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConditionToken::Comparison { lhs, comparator, rhs } => write!(f, "{} {} {}", lhs, comparator, rhs),
+            ConditionToken::Not { value } => write!(f, "not {}", value.grouped(2)),
+            ConditionToken::And { lhs, rhs } => write!(f, "{} and {}", lhs.grouped(1), rhs.grouped(1)),
+            ConditionToken::Or { lhs, rhs } => write!(f, "{} or {}", lhs, rhs)
+        }
+    }
+}
+
+impl TreeViewElement for ConditionToken {
+    fn to_tree_view(&self) -> Vec<String> {
+        return vec![format!("{{Condition: {}}}", self.tree())];
+    }
+}
+
+impl ConditionToken {
+    /// The classic condition `name != 0`.
+    pub fn against_zero(name: NameToken) -> Self {
+        ConditionToken::Comparison {
+            lhs: AssignableToken::Name { value: name },
+            comparator: Comparator::NotEqual,
+            rhs: AssignableToken::Digit { value: DigitToken::new(Natural::zero()) }
+        }
+    }
+
+    /// `and` and `or` are evaluated lazily, so the right side may not be evaluated at all.
+    pub fn evaluate(&self, context: &mut ExecutionContext) -> Result<bool, WhileError> {
+        return match self {
+            ConditionToken::Comparison { lhs, comparator, rhs } => {
+                let lhs = lhs.evaluate(context)?;
+                let rhs = rhs.evaluate(context)?;
+
+                Ok(comparator.matches(lhs.cmp(&rhs)))
+            }
+            ConditionToken::Not { value } => Ok(!value.evaluate(context)?),
+            ConditionToken::And { lhs, rhs } => Ok(lhs.evaluate(context)? && rhs.evaluate(context)?),
+            ConditionToken::Or { lhs, rhs } => Ok(lhs.evaluate(context)? || rhs.evaluate(context)?)
+        };
+    }
+
+    /// Every assignable of the condition, in source order.
+    pub fn assignables(&self) -> Vec<&AssignableToken> {
+        return match self {
+            ConditionToken::Comparison { lhs, rhs, .. } => vec![lhs, rhs],
+            ConditionToken::Not { value } => value.assignables(),
+            ConditionToken::And { lhs, rhs } | ConditionToken::Or { lhs, rhs } => {
+                let mut assignables = lhs.assignables();
+                assignables.extend(rhs.assignables());
+                assignables
+            }
+        };
+    }
+
+    fn precedence(&self) -> u8 {
+        return match self {
+            ConditionToken::Or { .. } => 0,
+            ConditionToken::And { .. } => 1,
+            ConditionToken::Not { .. } | ConditionToken::Comparison { .. } => 2
+        };
+    }
+
+    /// The operands are shown with their own tree view, combined conditions in parentheses like
+    /// the expressions.
+    fn tree(&self) -> String {
+        return match self {
+            ConditionToken::Comparison { lhs, comparator, rhs } => format!("{} {} {}", lhs.to_tree_view()[0], comparator, rhs.to_tree_view()[0]),
+            ConditionToken::Not { value } => format!("(not {})", value.tree()),
+            ConditionToken::And { lhs, rhs } => format!("({} and {})", lhs.tree(), rhs.tree()),
+            ConditionToken::Or { lhs, rhs } => format!("({} or {})", lhs.tree(), rhs.tree())
+        };
+    }
+
+    /// Puts the condition in parentheses if it binds weaker than `precedence`.
+    fn grouped(&self, precedence: u8) -> String {
+        if self.precedence() < precedence {
+            return format!("({})", self);
+        }
+
+        return self.to_string();
+    }
+
+    fn grouped_source(&self, precedence: u8) -> String {
+        if self.precedence() < precedence {
+            return format!("({})", self.to_source()[0]);
+        }
+
+        return self.to_source()[0].clone();
+    }
+}
+
+impl SourceViewElement for ConditionToken {
+    fn to_source(&self) -> Vec<String> {
+        let source = match self {
+            ConditionToken::Comparison { lhs, comparator, rhs } => format!("{} {} {}", lhs.to_source()[0], comparator, rhs.to_source()[0]),
+            ConditionToken::Not { value } => format!("not {}", value.grouped_source(2)),
+            ConditionToken::And { lhs, rhs } => format!("{} and {}", lhs.grouped_source(1), rhs.grouped_source(1)),
+            ConditionToken::Or { lhs, rhs } => format!("{} or {}", lhs.to_source()[0], rhs.to_source()[0])
+        };
+
+        return vec![source];
+    }
+}
@@ -0,0 +1,5 @@
+mod comparator;
+mod condition_token;
+
+pub use comparator::Comparator;
+pub use condition_token::ConditionToken;
@@ -0,0 +1,37 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArithmeticOperator {
+    Add,
+    /// Monus for natural numbers, `max(0, x - y)`.
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    /// Power, `x ^ y`, which binds strongest and groups from the right.
+    Pow
+}
+
+impl Display for ArithmeticOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArithmeticOperator::Add => write!(f, "+"),
+            ArithmeticOperator::Sub => write!(f, "-"),
+            ArithmeticOperator::Mul => write!(f, "*"),
+            ArithmeticOperator::Div => write!(f, "/"),
+            ArithmeticOperator::Rem => write!(f, "%"),
+            ArithmeticOperator::Pow => write!(f, "^")
+        }
+    }
+}
+
+impl ArithmeticOperator {
+    /// Operators with a higher precedence bind stronger.
+    pub fn precedence(&self) -> u8 {
+        return match self {
+            ArithmeticOperator::Add | ArithmeticOperator::Sub => 0,
+            ArithmeticOperator::Mul | ArithmeticOperator::Div | ArithmeticOperator::Rem => 1,
+            ArithmeticOperator::Pow => 2
+        };
+    }
+}
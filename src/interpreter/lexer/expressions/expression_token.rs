@@ -0,0 +1,67 @@
+use std::fmt::{Display, Formatter};
+use crate::interpreter::errors::WhileError;
+use crate::interpreter::executor_states::ExecutionContext;
+use crate::interpreter::lexer::expressions::ArithmeticOperator;
+use crate::interpreter::lexer::models::AssignableToken;
+use crate::interpreter::models::Value;
+use crate::interpreter::utils::logging::{SourceViewElement, TreeViewElement};
+
+/// A binary arithmetic expression. Parentheses of the source only shape the tree, they aren't stored.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExpressionToken {
+    pub lhs: Box<AssignableToken>,
+    pub operator: ArithmeticOperator,
+    pub rhs: Box<AssignableToken>
+}
+
+impl Display for ExpressionToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Expression Token: ({} {} {})", self.lhs, self.operator, self.rhs)
+    }
+}
+
+impl TreeViewElement for ExpressionToken {
+    fn to_tree_view(&self) -> Vec<String> {
+        return vec![format!("({} {} {})", self.lhs.to_tree_view()[0], self.operator, self.rhs.to_tree_view()[0])];
+    }
+}
+
+impl ExpressionToken {
+    pub fn new(lhs: AssignableToken, operator: ArithmeticOperator, rhs: AssignableToken) -> Self {
+        ExpressionToken {
+            lhs: Box::new(lhs),
+            operator,
+            rhs: Box::new(rhs)
+        }
+    }
+
+    /// Both operands are evaluated from left to right before the operator is applied.
+    pub fn evaluate(&self, context: &mut ExecutionContext) -> Result<Value, WhileError> {
+        let lhs = self.lhs.evaluate(context)?;
+        let rhs = self.rhs.evaluate(context)?;
+
+        return context.calculate(self.operator, &lhs, &rhs);
+    }
+
+    /// The source of an operand, in parentheses if `needs_parentheses` holds for the precedence of its operator.
+    fn grouped(operand: &AssignableToken, needs_parentheses: impl Fn(u8) -> bool) -> String {
+        return match operand {
+            AssignableToken::Expression { value } if needs_parentheses(value.operator.precedence()) => format!("({})", operand.to_source()[0]),
+            _ => operand.to_source()[0].clone()
+        };
+    }
+}
+
+impl SourceViewElement for ExpressionToken {
+    /// Operands are put into parentheses where the tree differs from the precedence and
+    /// associativity of the operators, e.g. `a - (b - c)` or `(a ^ b) ^ c`.
+    fn to_source(&self) -> Vec<String> {
+        let precedence = self.operator.precedence();
+        let right_associative = self.operator == ArithmeticOperator::Pow;
+
+        let lhs = ExpressionToken::grouped(&self.lhs, |other| other < precedence || other == precedence && right_associative);
+        let rhs = ExpressionToken::grouped(&self.rhs, |other| other < precedence || other == precedence && !right_associative);
+
+        return vec![format!("{} {} {}", lhs, self.operator, rhs)];
+    }
+}
@@ -0,0 +1,5 @@
+mod arithmetic_operator;
+mod expression_token;
+
+pub use arithmetic_operator::ArithmeticOperator;
+pub use expression_token::ExpressionToken;
@@ -0,0 +1,91 @@
+use std::fmt::{Display, Formatter};
+use crate::interpreter::errors::WhileError;
+use crate::interpreter::executor_states::ExecutionContext;
+use crate::interpreter::lexer::conditions::ConditionToken;
+use crate::interpreter::lexer::scopes::InnerBodyScope;
+use crate::interpreter::models::{BodyExecutor, ControlFlow, SourceLocation};
+use crate::interpreter::utils::logging::{SourceViewElement, TreeViewElement};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IfToken {
+    pub condition: ConditionToken,
+    pub scope: InnerBodyScope,
+    pub else_scope: Option<InnerBodyScope>,
+    pub location: SourceLocation
+}
+
+impl IfToken {
+    pub fn new(condition: ConditionToken, scope: InnerBodyScope, else_scope: Option<InnerBodyScope>, location: SourceLocation) -> Self {
+        IfToken {
+            condition,
+            scope,
+            else_scope,
+            location
+        }
+    }
+
+    /// Runs one of the branches. A jump or a return inside the branch is passed on to the enclosing loop or method.
+    pub fn evaluate(&self, context: &mut ExecutionContext) -> Result<ControlFlow, WhileError> {
+        let condition = self.condition.evaluate(context)?;
+        context.trace(|_| format!("if {}:  => {}", self.condition.to_source()[0], condition));
+
+        let scope = match condition {
+            true => &self.scope,
+            false => match &self.else_scope {
+                Some(else_scope) => else_scope,
+                None => return Ok(ControlFlow::Normal)
+            }
+        };
+
+        let body_executor = BodyExecutor {
+            scope: scope.stack.clone()
+        };
+
+        return context.traced_block(|context| body_executor.execute(context));
+    }
+}
+
+impl Display for IfToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "if {}", self.condition)
+    }
+}
+
+impl TreeViewElement for IfToken {
+    fn to_tree_view(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        lines.push(        "├── If Token:".to_string());
+        lines.push(format!("   ├── Header: {{if condition: {}}}", self.condition.to_tree_view()[0]));
+        lines.push(        "   ├── Scope:".to_string());
+
+        for temp_line in self.scope.to_tree_view() {
+            lines.push(format!("      {}", temp_line));
+        }
+
+        if let Some(else_scope) = &self.else_scope {
+            lines.push(    "   └── Else Scope:".to_string());
+
+            for temp_line in else_scope.to_tree_view() {
+                lines.push(format!("      {}", temp_line));
+            }
+        }
+
+        return lines;
+    }
+}
+
+impl SourceViewElement for IfToken {
+    fn to_source(&self) -> Vec<String> {
+        let mut lines = vec![format!("if {}:", self.condition.to_source()[0])];
+        lines.extend(self.scope.to_source());
+        lines.push("#".to_string());
+
+        if let Some(else_scope) = &self.else_scope {
+            lines.push("else:".to_string());
+            lines.extend(else_scope.to_source());
+            lines.push("#".to_string());
+        }
+
+        return lines;
+    }
+}
@@ -0,0 +1,3 @@
+mod if_token;
+
+pub use if_token::IfToken;
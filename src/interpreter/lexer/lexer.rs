@@ -1,127 +1,57 @@
-use std::borrow::Borrow;
+use std::collections::HashSet;
+use crate::interpreter::errors::WhileError;
 use crate::interpreter::models::CodeLine;
-use crate::interpreter::lexer::methods::MethodToken;
-use crate::interpreter::lexer::models::Stackable;
+use crate::interpreter::lexer::parser::{Dialect, Parser};
 use crate::interpreter::lexer::scopes::TopLevelScope;
-use crate::interpreter::lexer::while_tokens::WhileToken;
-use crate::interpreter::utils::interpreter_watcher::pseudo_throw;
+use crate::interpreter::macros::MacroExpander;
 use crate::interpreter::utils::logging::Logger;
 
 pub struct Lexer {
-    logger: Logger
+    logger: Logger,
+    dialect: Dialect
 }
 
 impl Lexer {
     pub fn new(logger: Logger) -> Lexer {
+        return Lexer::with_dialect(logger, Dialect::Classic);
+    }
+
+    pub fn with_dialect(logger: Logger, dialect: Dialect) -> Lexer {
         Lexer {
-            logger
+            logger,
+            dialect
         }
     }
 
-    pub fn tokenize(&self, code_lines: Vec<CodeLine>) -> TopLevelScope {
-        let mut scope = TopLevelScope::new(self.logger.clone());
+    /// Builds the program tree, after the macros of the program are expanded. On errors the parser recovers
+    /// after the erroneous statement, at the end of the enclosing block or at the next method header,
+    /// so every syntax error of the program is reported.
+    pub fn tokenize(&self, code_lines: Vec<CodeLine>) -> Result<TopLevelScope, Vec<WhileError>> {
+        return self.tokenize_avoiding(code_lines, &HashSet::new());
+    }
 
-        for code_line in code_lines.borrow() as &Vec<CodeLine> {
+    /// Like `tokenize`, but the temporaries of the macros don't use any of the `names`.
+    pub fn tokenize_avoiding(&self, code_lines: Vec<CodeLine>, names: &HashSet<String>) -> Result<TopLevelScope, Vec<WhileError>> {
+        let code_lines = MacroExpander::expand_avoiding(&code_lines, names)?;
+
+        for code_line in &code_lines {
             self.logger.log(&format!("{}", code_line));
         }
 
-        let mut i = 0;
-
-        while i < code_lines.len() {
-            let mut current_line = code_lines.get(i).unwrap();
-            let token = scope.parse(&current_line);
-
-            if token.is_none() {
-                i += 1;
-                continue;
-            }
-
-            let token = token.unwrap();
-
-            if let Some(stackable) = token.borrow().to_stackable() {
-                scope.stack.push(stackable);
-                i += 1;
-                continue;
-            }
-
-            if let Some(method_header) = token.borrow().to_method_header() {
-                let method_header_string = method_header.to_string();
-                let mut method = MethodToken::new(method_header, code_lines.clone(), current_line.line_number as usize);
-
-                if (i + 1) >= code_lines.len() {
-                    pseudo_throw(format!("Method can't be empty at line: {}", i));
-                    return scope;
-                }
-
-                current_line = code_lines.get(i + 1).unwrap();
-                let method_token = method.parse(&current_line);
-
-                if method_token.is_none() {
-                    return scope;
-                }
-
-                let last_line = method.last_line() as usize;
-                scope.methods.push(method);
+        let mut parser = Parser::with_dialect(&code_lines, self.dialect);
 
-                if !method_token.unwrap().ends_with_return() {
-                    pseudo_throw(format!("Method {} must end with a return at line: {}", method_header_string ,(i + 1)));
-                    scope.methods.remove(scope.methods.len() - 1);
-                    return scope;
-                }
-
-                i = last_line;
-            }
-
-            if let Some(while_header_token) = token.borrow().to_while_header_token() {
-                let mut removed_while_from_stack = false;
-                let mut while_token = WhileToken::new(while_header_token, None,code_lines.clone());
-
-                let mut j = i + 1;
-
-                while j < code_lines.len() {
-                    let current_line = code_lines.get(j).unwrap();
-                    let inner_token = while_token.parse(current_line);
-
-                    if inner_token.is_some() {
-                        if let Some(stacked_while_token) = inner_token.as_ref().unwrap().to_while_token() {
-                            if !stacked_while_token.escape_token_found {
-                                pseudo_throw(format!("Missing escape token at line: {}", while_token.clone().scope.unwrap().last_visited));
-                                scope.stack.pop();
-                                removed_while_from_stack = true;
-                                break;
-                            }
-                        }
-                    }
-                    j = while_token.scope.as_ref().unwrap().last_visited as usize;
-
-                    if inner_token.is_some() {
-                        if inner_token.as_ref().unwrap().to_while_escape_token().is_some() {
-                            while_token.escape_token_found = true;
-                            break;
-                        }
-                    }
-
-                    j += 1;
-                }
-
-                scope.stack.push(Stackable::WhileToken { value : while_token.clone() });
-                i = j;
-
-                if !while_token.escape_token_found {
-                    if !removed_while_from_stack {
-                        pseudo_throw(format!("Missing escape token at line: {}", while_token.scope.unwrap().last_visited));
-                        scope.stack.pop();
-                    }
+        for token in parser.tokens() {
+            self.logger.log(&format!("{}", token));
+        }
 
-                    break;
-                }
-            }
+        let (methods, stack) = parser.parse_program()?;
 
-            i += 1;
-        }
+        let mut scope = TopLevelScope::new(self.logger.clone());
+        scope.methods = methods;
+        scope.stack = stack;
 
         scope.print();
 
-        return scope;
+        return Ok(scope);
     }
-}
\ No newline at end of file
+}
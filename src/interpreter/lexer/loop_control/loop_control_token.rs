@@ -0,0 +1,33 @@
+use std::fmt::{Display, Formatter};
+use crate::interpreter::utils::logging::{SourceViewElement, TreeViewElement};
+
+/// `break;` leaves the innermost `while` or `loop`, `continue;` starts its next iteration.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LoopControlToken {
+    Break,
+    Continue
+}
+
+impl Display for LoopControlToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoopControlToken::Break => write!(f, "break"),
+            LoopControlToken::Continue => write!(f, "continue")
+        }
+    }
+}
+
+impl TreeViewElement for LoopControlToken {
+    fn to_tree_view(&self) -> Vec<String> {
+        match self {
+            LoopControlToken::Break => vec!["Break token".to_string()],
+            LoopControlToken::Continue => vec!["Continue token".to_string()]
+        }
+    }
+}
+
+impl SourceViewElement for LoopControlToken {
+    fn to_source(&self) -> Vec<String> {
+        return vec![format!("{};", self)];
+    }
+}
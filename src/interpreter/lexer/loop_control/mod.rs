@@ -0,0 +1,3 @@
+mod loop_control_token;
+
+pub use loop_control_token::LoopControlToken;
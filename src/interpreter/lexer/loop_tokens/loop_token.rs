@@ -0,0 +1,86 @@
+use std::fmt::{Display, Formatter};
+use crate::interpreter::errors::WhileError;
+use crate::interpreter::executor_states::ExecutionContext;
+use crate::interpreter::lexer::assignables::NameToken;
+use crate::interpreter::lexer::scopes::InnerBodyScope;
+use crate::interpreter::models::{BodyExecutor, ControlFlow, Natural, SourceLocation};
+use crate::interpreter::utils::logging::{SourceViewElement, TreeViewElement};
+
+/// `loop x: ... #` of the LOOP language. Unlike a while loop it always terminates, the number of
+/// iterations is the value of the counter on entry and changing the counter in the body has no effect.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoopToken {
+    pub counter: NameToken,
+    pub scope: InnerBodyScope,
+    pub location: SourceLocation
+}
+
+impl LoopToken {
+    pub fn new(counter: NameToken, scope: InnerBodyScope, location: SourceLocation) -> Self {
+        LoopToken {
+            counter,
+            scope,
+            location
+        }
+    }
+
+    pub fn evaluate(&self, context: &mut ExecutionContext) -> Result<ControlFlow, WhileError> {
+        let counter = self.counter.evaluate(context)?;
+
+        // a negative counter of a signed numeric mode runs the body zero times
+        let mut remaining = match counter.is_negative() {
+            true => Natural::zero(),
+            false => counter.magnitude().clone()
+        };
+
+        let body_executor = BodyExecutor {
+            scope: self.scope.stack.clone()
+        };
+
+        let one = Natural::from(1u32);
+        context.trace(|_| format!("loop {}:  => {} times", self.counter.value, remaining));
+
+        while !remaining.is_zero() {
+            match context.traced_block(|context| body_executor.execute(context))? {
+                ControlFlow::Break => break,
+                ControlFlow::Return(value) => return Ok(ControlFlow::Return(value)),
+                ControlFlow::Normal | ControlFlow::Continue => {}
+            }
+
+            remaining = remaining.monus(&one);
+        }
+
+        Ok(ControlFlow::Normal)
+    }
+}
+
+impl Display for LoopToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "loop {}", self.counter.value)
+    }
+}
+
+impl TreeViewElement for LoopToken {
+    fn to_tree_view(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        lines.push(        "├── Loop Token:".to_string());
+        lines.push(format!("   ├── Header: {{loop counter: {}}}", self.counter.to_tree_view()[0]));
+        lines.push(        "   └── Scope:".to_string());
+
+        for temp_line in self.scope.to_tree_view() {
+            lines.push(format!("      {}", temp_line));
+        }
+
+        return lines;
+    }
+}
+
+impl SourceViewElement for LoopToken {
+    fn to_source(&self) -> Vec<String> {
+        let mut lines = vec![format!("loop {}:", self.counter.value)];
+        lines.extend(self.scope.to_source());
+        lines.push("#".to_string());
+
+        return lines;
+    }
+}
@@ -0,0 +1,3 @@
+mod loop_token;
+
+pub use loop_token::LoopToken;
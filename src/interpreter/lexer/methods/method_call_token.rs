@@ -1,17 +1,17 @@
 use std::fmt::{Display, Formatter};
-use crate::interpreter::executor_states::RunTime;
-use crate::interpreter::models::CodeLine;
-use crate::interpreter::lexer::assignables::{DigitToken, NameToken};
+use crate::interpreter::errors::{ErrorKind, WhileError};
+use crate::interpreter::executor_states::ExecutionContext;
+use crate::interpreter::models::{SourceLocation, Value};
+use crate::interpreter::lexer::assignables::NameToken;
 use crate::interpreter::lexer::models::AssignableToken;
-use crate::interpreter::lexer::variables::VariableToken;
 use crate::interpreter::utils::extension_methods::VecNameTokenExtension;
-use crate::interpreter::utils::interpreter_watcher::pseudo_throw;
-use crate::interpreter::utils::logging::TreeViewElement;
+use crate::interpreter::utils::logging::{SourceViewElement, TreeViewElement};
 
 #[derive(PartialEq, Debug)]
 pub struct MethodCallToken {
     pub parameters: Vec<AssignableToken>,
     pub name: NameToken,
+    pub location: SourceLocation,
 }
 
 impl Clone for MethodCallToken {
@@ -19,6 +19,7 @@ impl Clone for MethodCallToken {
         MethodCallToken {
             parameters: self.parameters.clone(),
             name: self.name.clone(),
+            location: self.location,
         }
     }
 }
@@ -36,120 +37,44 @@ impl Display for MethodCallToken {
 }
 
 impl MethodCallToken {
-    pub fn evaluate(&self) -> u32 {
-        // build parameter variables
-        // in a method call, the indent level is 1. so we need to add 1 to the indent level.
-        let method_token = RunTime::get_method_token(&self.name.value);
-
-
-        return if let Some(method_token) = method_token {
-            let mut parameters: Vec<VariableToken> = Vec::new();
-
-            for (i, assignable) in self.parameters.iter().enumerate() {
-                parameters.push(VariableToken::new(
-                    method_token.header_token.parameters[i].clone(),
-                    AssignableToken::Digit {
-                        value: DigitToken::new(assignable.evaluate()),
-                    },
-                ))
-            }
-
-            RunTime::push_parameter_variables(parameters);
-            let value = RunTime::get_value_from_method_name(&self.name.value);
-            RunTime::pop_variables();
-
-            value
-        } else {
-            pseudo_throw(format!("Method not found: \"{}\"", self.name.value));
-            0
-        }
-    }
-
-    pub fn parse(code_line: &CodeLine) -> Option<MethodCallToken> {
-        if !code_line.line.contains("(") || !code_line.line.contains(")") {
-            return None;
-        }
-
-        if code_line.line.contains("=") {
-            return None;
-        }
-
-        let first_bracket = code_line.line.find('(');
-        let last_bracket = code_line.line.rfind(')');
-
-        if first_bracket.is_none() || last_bracket.is_none() {
-            return None;
-        }
-
-        let first_bracket = first_bracket.unwrap();
-        let last_bracket = last_bracket.unwrap();
-
-        let parameter_str: &str = &code_line.line[first_bracket + 1..last_bracket];
-        let name_str = &code_line.line[0..first_bracket];
-
-        let name_token = NameToken::parse(name_str);
-        if let None = name_token {
-            return None;
+    pub fn evaluate(&self, context: &mut ExecutionContext) -> Result<Value, WhileError> {
+        let method_token = match context.get_method_token(&self.name.value) {
+            Some(method_token) => method_token,
+            None => return Err(WhileError::runtime_at(ErrorKind::MethodNotFound, format!("Method not found: \"{}\"", self.name.value), self.location))
+        };
+
+        if method_token.header_token.parameters.len() != self.parameters.len() {
+            return Err(WhileError::runtime_at(ErrorKind::WrongArgumentCount, format!("Method {} expects {} arguments, but got {}", self.name.value, method_token.header_token.parameters.len(), self.parameters.len()), self.location));
         }
 
+        // the arguments are evaluated in the scope of the caller
         let mut parameters = Vec::new();
-        if parameter_str.trim().len() > 0 {
-            parameters = parse_p(parameter_str);
 
-            if parameters.len() == 0 {
-                return None;
-            }
+        for (name, assignable) in method_token.header_token.parameters.iter().zip(&self.parameters) {
+            parameters.push((name.value.clone(), assignable.evaluate(context)?));
         }
 
-        return Some(MethodCallToken {
-            name: name_token.unwrap(),
-            parameters
+        context.trace(|_| {
+            let arguments = parameters.iter().map(|(_, value)| value.to_string()).collect::<Vec<String>>();
+            format!("call {}({})", self.name.value, arguments.join(", "))
         });
-    }
-}
 
-fn parse_p(parameter_string: &str) -> Vec<AssignableToken> {
-    let mut parameters: Vec<AssignableToken> = Vec::new();
-    let mut individual_parameters: Vec<&str> = Vec::new();
-    let mut counter = 0;
-    let mut current_start_index = 0;
+        // the statements of the method move the location, errors after the call belong to the caller
+        let location = context.location;
 
-    for (index, c) in parameter_string.chars().enumerate() {
-        match c {
-            '(' => counter += 1,
-            ')' => counter -= 1,
-            ',' => {
-                if counter == 0 {
-                    let value = &parameter_string[current_start_index..index].trim();
+        context.push_frame(parameters);
+        let value = context.traced_block(|context| method_token.execute(context));
+        context.pop_frame();
 
-                    if value.is_empty() {
-                        pseudo_throw("Parameter can't be empty".to_string());
-                        return vec![];
-                    }
-
-                    individual_parameters.push(value);
-                    current_start_index = index + 1;
-                }
-            }
-            _ => { }
-        }
-    }
+        context.location = location;
 
-    if counter != 0 {
-        pseudo_throw("Expected ')' at method call".to_string());
-        return vec![];
+        return value;
     }
+}
 
-    individual_parameters.push(&parameter_string[current_start_index..parameter_string.len()].trim());
-
-    for para in individual_parameters {
-        let assignable = AssignableToken::parse(&CodeLine::new_from_line(para));
-
-        if assignable.is_some() {
-            parameters.push(assignable.unwrap());
-        }
+impl SourceViewElement for MethodCallToken {
+    fn to_source(&self) -> Vec<String> {
+        let parameters = self.parameters.iter().map(|parameter| parameter.to_source()[0].clone()).collect::<Vec<String>>();
+        return vec![format!("{}({})", self.name.value, parameters.join(", "))];
     }
-
-
-    return parameters;
-}
\ No newline at end of file
+}
@@ -1,28 +1,28 @@
 use std::fmt::{Display, Formatter};
-use crate::interpreter::models::{BodyExecutor, CodeLine};
+use crate::interpreter::errors::WhileError;
+use crate::interpreter::executor_states::ExecutionContext;
+use crate::interpreter::models::{BodyExecutor, ControlFlow, SourceLocation, Value};
 use crate::interpreter::lexer::methods::MethodHeaderToken;
-use crate::interpreter::lexer::models::Token;
 use crate::interpreter::lexer::scopes::InnerBodyScope;
-use crate::interpreter::utils::interpreter_watcher::pseudo_throw;
-use crate::interpreter::utils::logging::TreeViewElement;
+use crate::interpreter::utils::logging::{SourceViewElement, TreeViewElement};
 
 #[derive(Clone)]
 pub struct MethodToken {
     pub header_token: MethodHeaderToken,
     pub scope: InnerBodyScope,
-
-    code_lines: Vec<CodeLine>,
-    start_index: usize,
-    last_visited_line: i32
+    pub location: SourceLocation
 }
 
 impl MethodToken {
-    pub fn execute(&self) -> u32 {
+    pub fn execute(&self, context: &mut ExecutionContext) -> Result<Value, WhileError> {
         let body_executor = BodyExecutor {
             scope: self.scope.stack.clone()
         };
 
-        body_executor.execute().unwrap_or(0)
+        return match body_executor.execute(context)? {
+            ControlFlow::Return(value) => Ok(value),
+            _ => Ok(Value::default())
+        };
     }
 }
 
@@ -33,57 +33,13 @@ impl Display for MethodToken {
 }
 
 impl MethodToken {
-    pub fn ends_with_return(&self) -> bool {
-        return self.scope.stack.last().unwrap().is_return_token();
-    }
-
-    pub fn new(header_token: MethodHeaderToken, code_lines: Vec<CodeLine>, start_index: usize) -> Self {
+    pub fn new(header_token: MethodHeaderToken, scope: InnerBodyScope, location: SourceLocation) -> Self {
         return MethodToken {
             header_token,
-            scope: InnerBodyScope::default(),
-            code_lines,
-            start_index,
-            last_visited_line: 0
+            scope,
+            location
         };
     }
-
-    pub fn last_line(&self) -> i32 {
-        return self.last_visited_line;
-    }
-
-    pub fn parse(&mut self, line: &CodeLine) -> Option<Self> {
-        self.scope = InnerBodyScope::new(Some(self.header_token.clone()), self.code_lines.clone());
-
-        let mut i = self.start_index;
-
-        while i < self.code_lines.len() {
-            let current_line = self.code_lines.get(i).unwrap();
-            let token = self.scope.parse(&current_line);
-
-            let header_token = MethodHeaderToken::parse(line);
-
-            if header_token.is_some() {
-                pseudo_throw("Can't define a method inside a method".to_string());
-                return None;
-            }
-
-            i = self.scope.last_visited as usize;
-            self.last_visited_line = i as i32;
-
-            if token.is_some() {
-                match token.unwrap() {
-                    Token::Return { .. } => {
-                        break;
-                    }
-                    _ => { }
-                }
-            }
-
-            i += 1;
-        }
-
-        return Some(self.clone());
-    }
 }
 
 impl TreeViewElement for MethodToken {
@@ -99,4 +55,13 @@ impl TreeViewElement for MethodToken {
 
         return lines;
     }
-}
\ No newline at end of file
+}
+
+impl SourceViewElement for MethodToken {
+    fn to_source(&self) -> Vec<String> {
+        let mut lines = self.header_token.to_source();
+        lines.extend(self.scope.to_source());
+
+        return lines;
+    }
+}
@@ -7,4 +7,5 @@ mod type_token;
 pub use method_call_token::MethodCallToken;
 pub use method_header_token::MethodHeaderToken;
 pub use method_token::MethodToken;
-pub use return_token::ReturnToken;
\ No newline at end of file
+pub use return_token::ReturnToken;
+pub use type_token::TypeToken;
\ No newline at end of file
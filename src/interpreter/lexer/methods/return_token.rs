@@ -1,15 +1,16 @@
 use std::fmt::{Display, Formatter};
-use crate::interpreter::models::CodeLine;
+use crate::interpreter::errors::WhileError;
+use crate::interpreter::models::{CodeLine, SourceLocation};
 use crate::interpreter::lexer::methods::MethodHeaderToken;
-use crate::interpreter::lexer::methods::type_token::TypeToken;
 use crate::interpreter::lexer::models::AssignableToken;
-use crate::interpreter::utils::interpreter_watcher::pseudo_throw;
-use crate::interpreter::utils::logging::TreeViewElement;
+use crate::interpreter::lexer::parser::Parser;
+use crate::interpreter::utils::logging::{SourceViewElement, TreeViewElement};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ReturnToken {
     pub return_value: Option<AssignableToken>,
-    pub header: Option<MethodHeaderToken>
+    pub header: Option<MethodHeaderToken>,
+    pub location: SourceLocation
 }
 
 impl Display for ReturnToken {
@@ -19,44 +20,42 @@ impl Display for ReturnToken {
 }
 
 impl ReturnToken {
-    pub fn new(header: Option<MethodHeaderToken>) -> Self {
+    pub fn new(header: Option<MethodHeaderToken>, location: SourceLocation) -> Self {
         ReturnToken {
             return_value: None,
-            header
+            header,
+            location
         }
     }
 
-    pub fn parse(&mut self, line: &CodeLine) -> Option<ReturnToken> {
-        let split = line.line.split(&[' ', ';'][..])
-            .filter(|&s| !s.is_empty() && !s.trim().is_empty())
-            .collect::<Vec<&str>>();
+    pub fn parse(&mut self, line: &CodeLine) -> Result<Option<ReturnToken>, WhileError> {
+        let mut parser = Parser::new(std::slice::from_ref(line));
 
-        if split[0] != "return" || self.header.is_none() {
-            return None;
+        if self.header.is_none() || !parser.at_return() {
+            return Ok(None);
         }
 
-        if split.len() == 1 && self.header.as_ref().unwrap().return_type != TypeToken::Void {
-            pseudo_throw(format!("The method is not returning the expected value at line: {}", line.line_number));
-            return None;
-        } else if split.len() > 2 {
-            pseudo_throw(format!("Too many returning variables at line: {}", line.line_number));
-            return None;
-        }
-
-        if split.len() == 2 {
-            self.return_value = AssignableToken::parse(&CodeLine::new_from_line(split[1]));
-        }
+        *self = parser.return_statement(self.header.as_ref())?;
+        parser.expect_end()?;
 
-        return Some(self.clone());
+        return Ok(Some(self.clone()));
     }
 }
 
 impl TreeViewElement for ReturnToken {
     fn to_tree_view(&self) -> Vec<String> {
-        if self.return_value.is_none() {
-            vec![format!("Return token")]
-        } else {
-            vec![format!("Return: {}", self.return_value.as_ref().unwrap().to_tree_view()[0])]
+        match &self.return_value {
+            None => vec!["Return token".to_string()],
+            Some(return_value) => vec![format!("Return: {}", return_value.to_tree_view()[0])]
         }
     }
-}
\ No newline at end of file
+}
+
+impl SourceViewElement for ReturnToken {
+    fn to_source(&self) -> Vec<String> {
+        return match &self.return_value {
+            None => vec!["return;".to_string()],
+            Some(return_value) => vec![format!("return {};", return_value.to_source()[0])]
+        };
+    }
+}
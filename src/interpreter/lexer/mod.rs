@@ -4,7 +4,16 @@ pub mod methods;
 pub mod variables;
 pub mod scopes;
 pub mod while_tokens;
+pub mod conditions;
+pub mod expressions;
+pub mod if_tokens;
+pub mod loop_tokens;
+pub mod loop_control;
 pub mod operators;
+pub mod output;
+pub mod scanner;
+pub mod parser;
+#[allow(clippy::module_inception)]
 mod lexer;
 
 pub use lexer::Lexer;
\ No newline at end of file
@@ -1,14 +1,19 @@
 use std::fmt::{Debug, Display, Formatter};
-use crate::interpreter::models::CodeLine;
+use crate::interpreter::errors::WhileError;
+use crate::interpreter::executor_states::ExecutionContext;
+use crate::interpreter::models::{CodeLine, Value};
 use crate::interpreter::lexer::assignables::{DigitToken, NameToken};
+use crate::interpreter::lexer::expressions::ExpressionToken;
 use crate::interpreter::lexer::methods::MethodCallToken;
-use crate::interpreter::utils::logging::TreeViewElement;
+use crate::interpreter::lexer::parser::Parser;
+use crate::interpreter::utils::logging::{SourceViewElement, TreeViewElement};
 
 #[derive(Clone, PartialEq)]
 pub enum AssignableToken {
     Name { value: NameToken },
     Digit { value: DigitToken },
     MethodCall { value: MethodCallToken },
+    Expression { value: ExpressionToken },
 }
 
 impl Display for AssignableToken {
@@ -17,6 +22,7 @@ impl Display for AssignableToken {
             AssignableToken::Name { value } => write!(f, "{}", value),
             AssignableToken::Digit { value } => write!(f, "{}", value),
             AssignableToken::MethodCall { value } => write!(f, "{}", value),
+            AssignableToken::Expression { value } => write!(f, "{}", value),
         }
     }
 }
@@ -26,7 +32,8 @@ impl Debug for AssignableToken {
         match self {
             AssignableToken::Name { value } => write!(f, "{}", value),
             AssignableToken::Digit { value } => write!(f, "{}", value),
-            AssignableToken::MethodCall { value } => write!(f, "{}", value)
+            AssignableToken::MethodCall { value } => write!(f, "{}", value),
+            AssignableToken::Expression { value } => write!(f, "{}", value)
         }
     }
 }
@@ -36,82 +43,51 @@ impl TreeViewElement for AssignableToken {
         match self {
             AssignableToken::Name { value } => value.to_tree_view(),
             AssignableToken::Digit { value } => value.to_tree_view(),
-            AssignableToken::MethodCall { value } => value.to_tree_view()
+            AssignableToken::MethodCall { value } => value.to_tree_view(),
+            AssignableToken::Expression { value } => value.to_tree_view()
         }
     }
 }
 
 impl AssignableToken {
-    pub fn evaluate(&self) -> u32 {
+    pub fn evaluate(&self, context: &mut ExecutionContext) -> Result<Value, WhileError> {
         match self {
             AssignableToken::Digit { value } => {
-                value.evaluate()
+                context.literal(&value.evaluate())
             }
             AssignableToken::MethodCall { value } => {
-                value.evaluate()
+                value.evaluate(context)
             }
             AssignableToken::Name { value } => {
-                value.evaluate()
+                value.evaluate(context)
+            }
+            AssignableToken::Expression { value } => {
+                value.evaluate(context)
             }
         }
     }
 
-    pub fn parse(code_line: &CodeLine) -> Option<Self> {
-        let name_assignment_token = NameToken::parse(&code_line.line);
-        if let Some(value) = name_assignment_token {
-            return Some(AssignableToken::Name { value });
-        }
+    pub fn parse(code_line: &CodeLine) -> Result<Option<Self>, WhileError> {
+        let mut parser = Parser::new(std::slice::from_ref(code_line));
 
-        let method_assignment_token = MethodCallToken::parse(code_line);
-        if let Some(value) = method_assignment_token {
-            return Some(AssignableToken::MethodCall { value });
+        if !parser.at_assignable() {
+            return Ok(None);
         }
 
-        let digit_assignment_token = DigitToken::parse(&code_line.line);
+        let assignable_token = parser.parse_assignable()?;
+        parser.expect_end()?;
 
-        return match digit_assignment_token {
-            Some(value) => {
-                Some(AssignableToken::Digit { value })
-            }
-            None => {
-                None
-            }
-        };
+        return Ok(Some(assignable_token));
     }
+}
 
-    pub fn add_assign(&mut self, rhs: Self) {
-        let rhs_value = match rhs {
-            AssignableToken::Name { ref value } => {
-                value.evaluate()
-            }
-            AssignableToken::Digit { value } => {
-                value.evaluate()
-            }
-            AssignableToken::MethodCall { value } => {
-                value.evaluate()
-            }
-        };
-
-        *self = AssignableToken::Digit {
-            value: DigitToken::new(self.evaluate() + rhs_value)
-        }
-    }
-
-    pub fn sub_assign(&mut self, rhs: Self) {
-        let rhs_value = match rhs {
-            AssignableToken::Name { ref value } => {
-                value.evaluate()
-            }
-            AssignableToken::Digit { value } => {
-                value.evaluate()
-            }
-            AssignableToken::MethodCall { value } => {
-                value.evaluate()
-            }
-        };
-
-        *self = AssignableToken::Digit {
-            value: DigitToken::new(self.evaluate() - rhs_value)
+impl SourceViewElement for AssignableToken {
+    fn to_source(&self) -> Vec<String> {
+        match self {
+            AssignableToken::Name { value } => value.to_source(),
+            AssignableToken::Digit { value } => value.to_source(),
+            AssignableToken::MethodCall { value } => value.to_source(),
+            AssignableToken::Expression { value } => value.to_source()
         }
     }
-}
\ No newline at end of file
+}
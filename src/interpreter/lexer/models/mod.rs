@@ -1,28 +1,47 @@
 mod assignable_token;
-mod token;
 
 pub use assignable_token::AssignableToken;
-pub use token::Token;
 
+use crate::interpreter::lexer::if_tokens::IfToken;
+use crate::interpreter::lexer::loop_control::LoopControlToken;
+use crate::interpreter::lexer::loop_tokens::LoopToken;
 use crate::interpreter::lexer::methods::{MethodCallToken, ReturnToken};
-use crate::interpreter::lexer::operators::AdditiveOperatorToken;
+use crate::interpreter::lexer::operators::OperatorToken;
+use crate::interpreter::lexer::output::WritelnToken;
 use crate::interpreter::lexer::variables::VariableToken;
 use crate::interpreter::lexer::while_tokens::WhileToken;
+use crate::interpreter::models::SourceLocation;
 
 #[derive(Clone, PartialEq, Debug)]
 pub enum Stackable {
     MethodCallToken { value: MethodCallToken },
     VariableToken { value: VariableToken },
     WhileToken { value: WhileToken },
+    LoopToken { value: LoopToken },
+    IfToken { value: IfToken },
     ReturnToken { value: ReturnToken },
-    AdditiveOperatorToken { value: AdditiveOperatorToken },
+    LoopControlToken { value: LoopControlToken },
+    OperatorToken { value: OperatorToken },
+    WritelnToken { value: WritelnToken },
 }
 
 impl Stackable {
     pub fn is_return_token(&self) -> bool {
-        match self {
-            Stackable::ReturnToken { .. } => true,
-            _ => false,
-        }
+        matches!(self, Stackable::ReturnToken { .. })
+    }
+
+    /// Where the statement starts, `break` and `continue` can't fail and have no location.
+    pub fn location(&self) -> Option<SourceLocation> {
+        return match self {
+            Stackable::MethodCallToken { value } => Some(value.location),
+            Stackable::VariableToken { value } => Some(value.location),
+            Stackable::WhileToken { value } => Some(value.location),
+            Stackable::LoopToken { value } => Some(value.location),
+            Stackable::IfToken { value } => Some(value.location),
+            Stackable::ReturnToken { value } => Some(value.location),
+            Stackable::OperatorToken { value } => Some(value.location),
+            Stackable::WritelnToken { value } => Some(value.location),
+            Stackable::LoopControlToken { .. } => None
+        };
     }
 }
\ No newline at end of file
@@ -1,4 +1,3 @@
-mod additive_operator_token;
+mod operator_token;
 
-pub use additive_operator_token::AdditiveOperatorToken;
-pub use additive_operator_token::Operator;
\ No newline at end of file
+pub use operator_token::OperatorToken;
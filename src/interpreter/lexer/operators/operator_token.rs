@@ -0,0 +1,51 @@
+use std::fmt::{Display, Formatter};
+use crate::interpreter::errors::WhileError;
+use crate::interpreter::models::{CodeLine, SourceLocation};
+use crate::interpreter::lexer::assignables::NameToken;
+use crate::interpreter::lexer::expressions::ArithmeticOperator;
+use crate::interpreter::lexer::models::AssignableToken;
+use crate::interpreter::lexer::parser::Parser;
+use crate::interpreter::utils::logging::{SourceViewElement, TreeViewElement};
+
+/// A compound assignment like `x += y` or `x ^= 2`, which is `x = x <operator> y`
+/// with the semantics of the operator in the current numeric mode.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OperatorToken {
+    pub name: NameToken,
+    pub operator: ArithmeticOperator,
+    pub rhs_operand: AssignableToken,
+    pub location: SourceLocation
+}
+
+impl Display for OperatorToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}= {}", self.name, self.operator, self.rhs_operand)
+    }
+}
+
+impl OperatorToken {
+    pub fn parse(line: &CodeLine) -> Result<Option<OperatorToken>, WhileError> {
+        let mut parser = Parser::new(std::slice::from_ref(line));
+
+        if !parser.at_operator() {
+            return Ok(None);
+        }
+
+        let operator_token = parser.operator()?;
+        parser.expect_end()?;
+
+        return Ok(Some(operator_token));
+    }
+}
+
+impl TreeViewElement for OperatorToken {
+    fn to_tree_view(&self) -> Vec<String> {
+        return vec![format!("Operator token: {{name: {}, operator: {}=, RHS: {}}}", self.name.value, self.operator, self.rhs_operand.to_tree_view()[0])];
+    }
+}
+
+impl SourceViewElement for OperatorToken {
+    fn to_source(&self) -> Vec<String> {
+        return vec![format!("{} {}= {};", self.name.value, self.operator, self.rhs_operand.to_source()[0])];
+    }
+}
@@ -0,0 +1,3 @@
+mod writeln_token;
+
+pub use writeln_token::WritelnToken;
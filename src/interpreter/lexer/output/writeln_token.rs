@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+use crate::interpreter::errors::WhileError;
+use crate::interpreter::executor_states::ExecutionContext;
+use crate::interpreter::lexer::models::AssignableToken;
+use crate::interpreter::models::SourceLocation;
+use crate::interpreter::utils::logging::{SourceViewElement, TreeViewElement};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct WritelnToken {
+    pub value: AssignableToken,
+    pub location: SourceLocation
+}
+
+impl Display for WritelnToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "writeln({})", self.value)
+    }
+}
+
+impl TreeViewElement for WritelnToken {
+    fn to_tree_view(&self) -> Vec<String> {
+        vec![format!("Writeln token: {}", self.value.to_tree_view()[0])]
+    }
+}
+
+impl WritelnToken {
+    pub fn evaluate(&self, context: &mut ExecutionContext) -> Result<(), WhileError> {
+        let value = self.value.evaluate(context)?;
+        context.write_line(&value.to_string());
+
+        Ok(())
+    }
+}
+
+impl SourceViewElement for WritelnToken {
+    fn to_source(&self) -> Vec<String> {
+        return vec![format!("writeln({});", self.value.to_source()[0])];
+    }
+}
@@ -0,0 +1,24 @@
+/// The language accepted by the parser.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Dialect {
+    /// Strict While: a loop condition can only be `NAME != 0`.
+    #[default]
+    Classic,
+    /// Conditions compare any two assignables with `!=`, `==`, `<`, `<=`, `>` or `>=`
+    /// and can be combined with `not`, `and` and `or`.
+    Extended,
+    /// Classic conditions, but `while` is rejected and the analysis rejects recursive methods,
+    /// so every program is primitive recursive by construction.
+    Loop,
+}
+
+impl Dialect {
+    pub fn analyse(dialect: &str) -> Option<Dialect> {
+        return match dialect {
+            "classic" => Some(Dialect::Classic),
+            "extended" => Some(Dialect::Extended),
+            "loop" => Some(Dialect::Loop),
+            _ => None
+        }
+    }
+}
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod parser;
+mod dialect;
+
+pub use parser::Parser;
+pub use dialect::Dialect;
@@ -0,0 +1,932 @@
+use crate::interpreter::errors::{ErrorKind, WhileError};
+use crate::interpreter::lexer::assignables::{DigitToken, NameToken};
+use crate::interpreter::lexer::conditions::{Comparator, ConditionToken};
+use crate::interpreter::lexer::expressions::{ArithmeticOperator, ExpressionToken};
+use crate::interpreter::lexer::if_tokens::IfToken;
+use crate::interpreter::lexer::loop_control::LoopControlToken;
+use crate::interpreter::lexer::loop_tokens::LoopToken;
+use crate::interpreter::lexer::methods::{MethodCallToken, MethodHeaderToken, MethodToken, ReturnToken, TypeToken};
+use crate::interpreter::lexer::models::{AssignableToken, Stackable};
+use crate::interpreter::lexer::operators::OperatorToken;
+use crate::interpreter::lexer::output::WritelnToken;
+use crate::interpreter::lexer::parser::Dialect;
+use crate::interpreter::lexer::scanner::{Keyword, Scanner, SourceToken, SourceTokenKind};
+use crate::interpreter::lexer::scopes::InnerBodyScope;
+use crate::interpreter::lexer::variables::VariableToken;
+use crate::interpreter::lexer::while_tokens::{WhileHeaderToken, WhileToken};
+use crate::interpreter::models::{CodeLine, Natural, SourceLocation};
+
+/// Recursive descent parser over the tokens of the scanner.
+///
+/// ```text
+/// program    := (method | statement)*
+/// method     := TYPE NAME "(" (NAME ("," NAME)*)? ")" ":" statement* return
+/// statement  := variable | operator | call ";" | writeln | while | loop | if | return | jump
+/// variable   := NAME "=" assignable ";"
+/// operator   := NAME ("+=" | "-=" | "*=" | "/=" | "%=" | "^=") assignable ";"
+/// while      := "while" condition ":" statement* "#"
+/// loop       := "loop" NAME ":" statement* "#"
+/// if         := "if" condition ":" statement* "#" ("else" ":" statement* "#")?
+/// writeln    := "writeln" "(" assignable ")" ";"
+/// return     := "return" assignable? ";"
+/// jump       := ("break" | "continue") ";"
+/// assignable := term (("+" | "-") term)*
+/// term       := power (("*" | "/" | "%") power)*
+/// power      := operand ("^" power)?
+/// operand    := NUMBER | NAME | call | "(" assignable ")"
+/// call       := NAME "(" (assignable ("," assignable)*)? ")"
+/// ```
+///
+/// In the classic dialect a condition is `NAME "!=" "0"`. The extended dialect accepts
+///
+/// ```text
+/// condition  := and ("or" and)*
+/// and        := not ("and" not)*
+/// not        := "not" not | "(" condition ")" | assignable COMPARATOR assignable
+/// ```
+///
+/// The loop dialect uses classic conditions and rejects `while`.
+///
+/// A jump can only be used inside a `while` or `loop` body and targets the innermost one.
+/// A method ends with the first return statement of its body. Syntax errors are collected and
+/// parsing continues after the erroneous statement, so all errors of a program are reported.
+pub struct Parser {
+    tokens: Vec<SourceToken>,
+    code_lines: Vec<CodeLine>,
+    position: usize,
+    dialect: Dialect,
+    loop_depth: usize,
+    diagnostics: Vec<WhileError>
+}
+
+impl Parser {
+    pub fn new(code_lines: &[CodeLine]) -> Self {
+        return Parser::with_dialect(code_lines, Dialect::Classic);
+    }
+
+    pub fn with_dialect(code_lines: &[CodeLine], dialect: Dialect) -> Self {
+        Parser {
+            tokens: Scanner::scan(code_lines),
+            code_lines: code_lines.to_vec(),
+            position: 0,
+            dialect,
+            loop_depth: 0,
+            diagnostics: Vec::new()
+        }
+    }
+
+    pub fn tokens(&self) -> &[SourceToken] {
+        return &self.tokens;
+    }
+
+    /// Parses the whole program into its methods and top level statements.
+    pub fn parse_program(&mut self) -> Result<(Vec<MethodToken>, Vec<Stackable>), Vec<WhileError>> {
+        let mut methods = Vec::new();
+        let mut stack = Vec::new();
+
+        while !self.check(&SourceTokenKind::End) {
+            if self.at_method_header() {
+                if let Some(method) = self.method() {
+                    methods.push(method);
+                }
+
+                continue;
+            }
+
+            if self.check(&SourceTokenKind::Hash) {
+                let error = self.error_at_current(ErrorKind::UnexpectedToken, "Unexpected \"#\" outside of a block".to_string());
+                self.diagnostics.push(error);
+                self.advance();
+                continue;
+            }
+
+            if let Some(stackable) = self.statement_or_recover(None) {
+                stack.push(stackable);
+            }
+        }
+
+        if !self.diagnostics.is_empty() {
+            let mut diagnostics = std::mem::take(&mut self.diagnostics);
+            diagnostics.sort_by_key(|diagnostic| diagnostic.line);
+
+            return Err(diagnostics);
+        }
+
+        return Ok((methods, stack));
+    }
+
+    /// Fails if there are tokens left, used after parsing a single construct.
+    pub fn expect_end(&mut self) -> Result<(), WhileError> {
+        if self.check(&SourceTokenKind::End) {
+            return Ok(());
+        }
+
+        return Err(self.error_at_current(ErrorKind::UnexpectedToken, format!("Unexpected \"{}\"", self.peek().kind)));
+    }
+
+    pub fn at_variable(&self) -> bool {
+        return self.at_name() && self.peek_at(1).kind == SourceTokenKind::Assign;
+    }
+
+    pub fn at_operator(&self) -> bool {
+        return self.at_name() && Parser::compound_operator(&self.peek_at(1).kind).is_some();
+    }
+
+    pub fn at_method_call(&self) -> bool {
+        return self.at_name() && self.peek_at(1).kind == SourceTokenKind::LeftParen;
+    }
+
+    pub fn at_assignable(&self) -> bool {
+        return self.at_name() || matches!(self.peek().kind, SourceTokenKind::Number(_) | SourceTokenKind::LeftParen);
+    }
+
+    pub fn at_method_header(&self) -> bool {
+        return matches!(self.peek().kind, SourceTokenKind::Keyword(Keyword::Num) | SourceTokenKind::Keyword(Keyword::Void));
+    }
+
+    pub fn at_while(&self) -> bool {
+        return self.check(&SourceTokenKind::Keyword(Keyword::While));
+    }
+
+    pub fn at_loop(&self) -> bool {
+        return self.check(&SourceTokenKind::Keyword(Keyword::Loop));
+    }
+
+    pub fn at_if(&self) -> bool {
+        return self.check(&SourceTokenKind::Keyword(Keyword::If));
+    }
+
+    pub fn at_writeln(&self) -> bool {
+        return self.check(&SourceTokenKind::Keyword(Keyword::Writeln));
+    }
+
+    pub fn at_return(&self) -> bool {
+        return self.check(&SourceTokenKind::Keyword(Keyword::Return));
+    }
+
+    pub fn at_loop_control(&self) -> bool {
+        return matches!(self.peek().kind, SourceTokenKind::Keyword(Keyword::Break) | SourceTokenKind::Keyword(Keyword::Continue));
+    }
+
+    fn method(&mut self) -> Option<MethodToken> {
+        let header_index = self.position;
+
+        let header = match self.method_header() {
+            Ok(header) => header,
+            Err(error) => {
+                self.diagnostics.push(error);
+                self.skip_method_body();
+                return None;
+            }
+        };
+
+        let header_token = self.tokens[header_index].clone();
+
+        if self.check(&SourceTokenKind::End) {
+            self.diagnostics.push(self.error_at(&header_token, ErrorKind::EmptyMethod, format!("Method {} can't be empty", header.name.value)));
+            return None;
+        }
+
+        let mut scope = InnerBodyScope::new(Some(header.clone()));
+
+        loop {
+            if self.check(&SourceTokenKind::End) || self.at_method_header() {
+                self.diagnostics.push(self.error_at(&header_token, ErrorKind::MissingReturn, format!("Method {} must end with a return", header.name.value)));
+                return None;
+            }
+
+            if self.at_return() {
+                let start = self.position;
+
+                return match self.return_statement(Some(&header)) {
+                    Ok(return_token) => {
+                        scope.stack.push(Stackable::ReturnToken { value: return_token });
+                        Some(MethodToken::new(header, scope, SourceLocation::new(header_token.line, header_token.column)))
+                    }
+                    Err(error) => {
+                        self.diagnostics.push(error);
+                        self.synchronize(start);
+                        None
+                    }
+                };
+            }
+
+            self.body_statement(&mut scope, Some(&header));
+        }
+    }
+
+    /// Skips the body of a method with a malformed header: up to and including its return
+    /// statement, or up to the next method header.
+    fn skip_method_body(&mut self) {
+        while !self.check(&SourceTokenKind::End) && !self.at_method_header() {
+            if self.at_return() {
+                let start = self.position;
+                self.synchronize(start);
+                return;
+            }
+
+            self.advance();
+        }
+    }
+
+    pub fn method_header(&mut self) -> Result<MethodHeaderToken, WhileError> {
+        let return_type = match self.advance().kind {
+            SourceTokenKind::Keyword(Keyword::Num) => TypeToken::Num,
+            _ => TypeToken::Void
+        };
+
+        let name = match &self.peek().kind {
+            SourceTokenKind::Name(name) => NameToken::new(name),
+            kind => return Err(self.error_at_current(ErrorKind::ExpectedName, format!("Expected method name, but found: {}", kind)))
+        };
+        self.advance();
+
+        if !self.check(&SourceTokenKind::LeftParen) {
+            return Err(self.error_at_current(ErrorKind::UnexpectedToken, format!("Expected \"(\" after method name, but found: {}", self.peek().kind)));
+        }
+        self.advance();
+
+        let mut parameters = Vec::new();
+
+        if !self.check(&SourceTokenKind::RightParen) {
+            loop {
+                match &self.peek().kind {
+                    SourceTokenKind::Name(name) => parameters.push(NameToken::new(name)),
+                    SourceTokenKind::Comma | SourceTokenKind::RightParen => return Err(self.error_at_current(ErrorKind::EmptyParameter, "Parameter can't be empty".to_string())),
+                    SourceTokenKind::Colon | SourceTokenKind::End => return Err(self.error_after_previous(ErrorKind::UnbalancedParentheses, "Expected ')' at method header".to_string())),
+                    kind => return Err(self.error_at_current(ErrorKind::ExpectedName, format!("Expected a parameter name, but found: \"{}\"", kind)))
+                }
+                self.advance();
+
+                if !self.check(&SourceTokenKind::Comma) {
+                    break;
+                }
+                self.advance();
+            }
+        }
+
+        if !self.check(&SourceTokenKind::RightParen) {
+            return Err(self.error_after_previous(ErrorKind::UnbalancedParentheses, "Expected ')' at method header".to_string()));
+        }
+        self.advance();
+
+        if !self.check(&SourceTokenKind::Colon) {
+            return Err(self.error_after_previous(ErrorKind::ExpectedColon, format!("Expected ':' after method definition, but found: {}", self.peek().kind)));
+        }
+        self.advance();
+
+        return Ok(MethodHeaderToken {
+            name,
+            parameters,
+            return_type
+        });
+    }
+
+    /// Parses a statement of a body into the scope, errors are collected.
+    fn body_statement(&mut self, scope: &mut InnerBodyScope, header: Option<&MethodHeaderToken>) {
+        if self.check(&SourceTokenKind::Hash) {
+            let error = self.error_at_current(ErrorKind::UnexpectedToken, "Unexpected \"#\" outside of a block".to_string());
+            self.diagnostics.push(error);
+            self.advance();
+            return;
+        }
+
+        if let Some(stackable) = self.statement_or_recover(header) {
+            scope.stack.push(stackable);
+        }
+    }
+
+    /// Parses a statement. On an error, the error is collected and parsing continues after it.
+    fn statement_or_recover(&mut self, header: Option<&MethodHeaderToken>) -> Option<Stackable> {
+        if self.at_while() {
+            return self.while_loop(header);
+        }
+
+        if self.at_loop() {
+            return self.loop_block(header);
+        }
+
+        if self.at_if() {
+            return self.if_block(header);
+        }
+
+        let start = self.position;
+
+        return match self.statement(header) {
+            Ok(stackable) => Some(stackable),
+            Err(error) => {
+                self.diagnostics.push(error);
+                self.synchronize(start);
+                None
+            }
+        };
+    }
+
+    fn statement(&mut self, header: Option<&MethodHeaderToken>) -> Result<Stackable, WhileError> {
+        if self.at_return() {
+            return match header {
+                Some(header) => Ok(Stackable::ReturnToken { value: self.return_statement(Some(header))? }),
+                None => Err(self.error_at_current(ErrorKind::InvalidReturn, "Return statement outside of a method".to_string()))
+            };
+        }
+
+        if self.at_loop_control() {
+            return Ok(Stackable::LoopControlToken { value: self.loop_control()? });
+        }
+
+        if self.at_writeln() {
+            return Ok(Stackable::WritelnToken { value: self.writeln()? });
+        }
+
+        if self.at_variable() {
+            return Ok(Stackable::VariableToken { value: self.variable()? });
+        }
+
+        if self.at_operator() {
+            return Ok(Stackable::OperatorToken { value: self.operator()? });
+        }
+
+        if self.at_method_call() {
+            let method_call_token = self.method_call()?;
+            self.expect_semicolon()?;
+
+            return Ok(Stackable::MethodCallToken { value: method_call_token });
+        }
+
+        if self.at_name() {
+            let name = self.advance();
+            return Err(self.error_at_current(ErrorKind::UnexpectedToken, format!("Expected \"=\", a compound assignment like \"+=\" or \"(\" after {}, but found: {}", name.kind, self.peek().kind)));
+        }
+
+        return Err(self.error_at_current(ErrorKind::UnexpectedToken, format!("Unexpected \"{}\"", self.peek().kind)));
+    }
+
+    pub fn variable(&mut self) -> Result<VariableToken, WhileError> {
+        let location = self.location();
+        let name = self.name();
+        self.advance();
+
+        let assignment = self.assignable("'='")?;
+        self.expect_semicolon()?;
+
+        return Ok(VariableToken::new(name, assignment, location));
+    }
+
+    pub fn operator(&mut self) -> Result<OperatorToken, WhileError> {
+        let location = self.location();
+        let name = self.name();
+
+        let operator = Parser::compound_operator(&self.advance().kind).unwrap_or(ArithmeticOperator::Add);
+
+        let rhs_operand = self.assignable(&format!("\"{}=\"", operator))?;
+        self.expect_semicolon()?;
+
+        return Ok(OperatorToken {
+            name,
+            operator,
+            rhs_operand,
+            location
+        });
+    }
+
+    fn compound_operator(kind: &SourceTokenKind) -> Option<ArithmeticOperator> {
+        return match kind {
+            SourceTokenKind::AddAssign => Some(ArithmeticOperator::Add),
+            SourceTokenKind::SubAssign => Some(ArithmeticOperator::Sub),
+            SourceTokenKind::MulAssign => Some(ArithmeticOperator::Mul),
+            SourceTokenKind::DivAssign => Some(ArithmeticOperator::Div),
+            SourceTokenKind::RemAssign => Some(ArithmeticOperator::Rem),
+            SourceTokenKind::PowAssign => Some(ArithmeticOperator::Pow),
+            _ => None
+        };
+    }
+
+    pub fn writeln(&mut self) -> Result<WritelnToken, WhileError> {
+        let location = self.location();
+        self.advance();
+
+        if !self.check(&SourceTokenKind::LeftParen) {
+            return Err(self.error_at_current(ErrorKind::UnexpectedToken, format!("Expected \"(\" after writeln, but found: {}", self.peek().kind)));
+        }
+        self.advance();
+
+        let value = self.assignable("writeln")?;
+
+        if !self.check(&SourceTokenKind::RightParen) {
+            return Err(self.error_after_previous(ErrorKind::UnbalancedParentheses, "Expected ')' at writeln".to_string()));
+        }
+        self.advance();
+
+        self.expect_semicolon()?;
+
+        return Ok(WritelnToken { value, location });
+    }
+
+    pub fn loop_control(&mut self) -> Result<LoopControlToken, WhileError> {
+        let keyword = self.advance();
+
+        let loop_control_token = match keyword.kind {
+            SourceTokenKind::Keyword(Keyword::Break) => LoopControlToken::Break,
+            _ => LoopControlToken::Continue
+        };
+
+        if self.loop_depth == 0 {
+            return Err(self.error_at(&keyword, ErrorKind::InvalidLoopControl, format!("{} statement outside of a loop", loop_control_token)));
+        }
+
+        self.expect_semicolon()?;
+
+        return Ok(loop_control_token);
+    }
+
+    fn while_loop(&mut self, header: Option<&MethodHeaderToken>) -> Option<Stackable> {
+        let while_token = self.peek().clone();
+
+        let header_token = self.while_header();
+        let header_token = self.recover_header(header_token, &while_token);
+
+        let scope = self.loop_body(&while_token, header, "while loop")?;
+
+        if self.dialect == Dialect::Loop {
+            self.diagnostics.push(self.error_at(&while_token, ErrorKind::WhileInLoopProgram, "A LOOP program can't use while, use loop instead".to_string()));
+            return None;
+        }
+
+        let mut while_token = WhileToken::new(header_token?, header.cloned(), SourceLocation::new(while_token.line, while_token.column));
+        while_token.scope = Some(scope);
+
+        return Some(Stackable::WhileToken { value: while_token });
+    }
+
+    pub fn while_header(&mut self) -> Result<WhileHeaderToken, WhileError> {
+        return Ok(WhileHeaderToken::new(self.block_header()?));
+    }
+
+    fn loop_block(&mut self, header: Option<&MethodHeaderToken>) -> Option<Stackable> {
+        let loop_token = self.peek().clone();
+
+        let counter = self.loop_header();
+        let counter = self.recover_header(counter, &loop_token);
+
+        let scope = self.loop_body(&loop_token, header, "loop")?;
+
+        return Some(Stackable::LoopToken { value: LoopToken::new(counter?, scope, SourceLocation::new(loop_token.line, loop_token.column)) });
+    }
+
+    pub fn loop_header(&mut self) -> Result<NameToken, WhileError> {
+        self.advance();
+
+        let counter = match &self.peek().kind {
+            SourceTokenKind::Name(name) => NameToken::new(name),
+            kind => return Err(self.error_at_current(ErrorKind::ExpectedName, format!("Expected a counter variable after loop, but found: {}", kind)))
+        };
+        self.advance();
+
+        if !self.check(&SourceTokenKind::Colon) {
+            return Err(self.error_after_previous(ErrorKind::ExpectedColon, format!("Expected a \":\" after loop header, but found: {}", self.peek().kind)));
+        }
+        self.advance();
+
+        return Ok(counter);
+    }
+
+    fn if_block(&mut self, header: Option<&MethodHeaderToken>) -> Option<Stackable> {
+        let if_token = self.peek().clone();
+
+        let condition = self.block_header();
+        let condition = self.recover_header(condition, &if_token);
+
+        let scope = self.block(&if_token, header, "if block")?;
+        let mut else_scope = None;
+
+        if self.check(&SourceTokenKind::Keyword(Keyword::Else)) {
+            let else_token = self.advance();
+
+            if self.check(&SourceTokenKind::Colon) {
+                self.advance();
+            } else {
+                self.diagnostics.push(self.error_after_previous(ErrorKind::ExpectedColon, format!("Expected a \":\" after else, but found: {}", self.peek().kind)));
+                self.skip_line(else_token.index);
+            }
+
+            else_scope = Some(self.block(&else_token, header, "else block")?);
+        }
+
+        return Some(Stackable::IfToken { value: IfToken::new(condition?, scope, else_scope, SourceLocation::new(if_token.line, if_token.column)) });
+    }
+
+    /// Reports the error of a block header right away and skips to its ":". The body is still parsed,
+    /// so its escape token isn't mistaken for a stray one, and an error in the body doesn't hide the
+    /// one of the header.
+    fn recover_header<T>(&mut self, header: Result<T, WhileError>, keyword: &SourceToken) -> Option<T> {
+        return match header {
+            Ok(header) => Some(header),
+            Err(error) => {
+                self.diagnostics.push(error);
+
+                while self.peek().index == keyword.index && !self.check(&SourceTokenKind::End) {
+                    if self.advance().kind == SourceTokenKind::Colon {
+                        break;
+                    }
+                }
+
+                None
+            }
+        };
+    }
+
+    /// Parses the statements of a block up to and including its escape token "#".
+    fn block(&mut self, opening_token: &SourceToken, header: Option<&MethodHeaderToken>, block_name: &str) -> Option<InnerBodyScope> {
+        let mut scope = InnerBodyScope::new(header.cloned());
+
+        while !self.check(&SourceTokenKind::Hash) {
+            // a method header can't be part of a body, so the escape token must be missing
+            if self.check(&SourceTokenKind::End) || self.at_method_header() {
+                self.diagnostics.push(self.error_at(opening_token, ErrorKind::MissingEscapeToken, format!("Missing escape token \"#\" for {}", block_name)));
+                return None;
+            }
+
+            self.body_statement(&mut scope, header);
+        }
+        self.advance();
+
+        return Some(scope);
+    }
+
+    /// A block in which `break` and `continue` are allowed.
+    fn loop_body(&mut self, opening_token: &SourceToken, header: Option<&MethodHeaderToken>, block_name: &str) -> Option<InnerBodyScope> {
+        self.loop_depth += 1;
+        let scope = self.block(opening_token, header, block_name);
+        self.loop_depth -= 1;
+
+        return scope;
+    }
+
+    /// Parses the keyword and condition of a block up to and including the ":".
+    fn block_header(&mut self) -> Result<ConditionToken, WhileError> {
+        let keyword = self.advance();
+
+        let condition = match self.dialect {
+            Dialect::Classic | Dialect::Loop => self.classic_condition(&keyword)?,
+            Dialect::Extended => self.condition()?
+        };
+
+        if !self.check(&SourceTokenKind::Colon) {
+            return Err(self.error_after_previous(ErrorKind::ExpectedColon, format!("Expected a \":\" after {} header, but found: {}", keyword.kind, self.peek().kind)));
+        }
+        self.advance();
+
+        return Ok(condition);
+    }
+
+    /// The only condition of classic While, `NAME != 0`.
+    fn classic_condition(&mut self, keyword: &SourceToken) -> Result<ConditionToken, WhileError> {
+        let name = match &self.peek().kind {
+            SourceTokenKind::Name(name) => NameToken::new(name),
+            kind => return Err(self.error_at_current(ErrorKind::ExpectedName, format!("Expected a name after {}, but found: {}", keyword.kind, kind)))
+        };
+        self.advance();
+
+        if !self.check(&SourceTokenKind::NotEqual) {
+            let hint = match self.peek_comparator() {
+                Some(_) => ". Other comparisons need the extended dialect",
+                None => ""
+            };
+
+            return Err(self.error_at_current(ErrorKind::UnexpectedToken, format!("Expected a != after {}, but found: {}{}", name.value, self.peek().kind, hint)));
+        }
+        self.advance();
+
+        match &self.peek().kind {
+            SourceTokenKind::Number(number) if Natural::parse(number).is_some_and(|number| number.is_zero()) => { }
+            kind => return Err(self.error_at_current(ErrorKind::UnexpectedToken, format!("Expected a \"0\" as comparer, but found: {}", kind)))
+        }
+        self.advance();
+
+        return Ok(ConditionToken::against_zero(name));
+    }
+
+    pub fn condition(&mut self) -> Result<ConditionToken, WhileError> {
+        let mut condition = self.conjunction()?;
+
+        while self.check(&SourceTokenKind::Keyword(Keyword::Or)) {
+            self.advance();
+            condition = ConditionToken::Or { lhs: Box::new(condition), rhs: Box::new(self.conjunction()?) };
+        }
+
+        return Ok(condition);
+    }
+
+    fn conjunction(&mut self) -> Result<ConditionToken, WhileError> {
+        let mut condition = self.negation()?;
+
+        while self.check(&SourceTokenKind::Keyword(Keyword::And)) {
+            self.advance();
+            condition = ConditionToken::And { lhs: Box::new(condition), rhs: Box::new(self.negation()?) };
+        }
+
+        return Ok(condition);
+    }
+
+    fn negation(&mut self) -> Result<ConditionToken, WhileError> {
+        if self.check(&SourceTokenKind::Keyword(Keyword::Not)) {
+            self.advance();
+            return Ok(ConditionToken::Not { value: Box::new(self.negation()?) });
+        }
+
+        if self.check(&SourceTokenKind::LeftParen) {
+            let start = self.position;
+            self.advance();
+
+            // "(a + b) < c" starts with a parenthesised assignable, not a parenthesised condition
+            match self.condition() {
+                Ok(condition) if self.check(&SourceTokenKind::RightParen) => {
+                    self.advance();
+                    return Ok(condition);
+                }
+                Ok(_) => return Err(self.error_after_previous(ErrorKind::UnbalancedParentheses, "Expected ')' at condition".to_string())),
+                Err(_) => self.position = start
+            }
+        }
+
+        let lhs = self.assignable("a condition")?;
+
+        let comparator = match self.peek_comparator() {
+            Some(comparator) => comparator,
+            None => return Err(self.error_at_current(ErrorKind::UnexpectedToken, format!("Expected a comparison, but found: {}", self.peek().kind)))
+        };
+        self.advance();
+
+        let rhs = self.assignable(&format!("\"{}\"", comparator))?;
+
+        return Ok(ConditionToken::Comparison { lhs, comparator, rhs });
+    }
+
+    fn peek_comparator(&self) -> Option<Comparator> {
+        return match self.peek().kind {
+            SourceTokenKind::NotEqual => Some(Comparator::NotEqual),
+            SourceTokenKind::Equal => Some(Comparator::Equal),
+            SourceTokenKind::Less => Some(Comparator::Less),
+            SourceTokenKind::LessEqual => Some(Comparator::LessEqual),
+            SourceTokenKind::Greater => Some(Comparator::Greater),
+            SourceTokenKind::GreaterEqual => Some(Comparator::GreaterEqual),
+            _ => None
+        };
+    }
+
+    pub fn return_statement(&mut self, header: Option<&MethodHeaderToken>) -> Result<ReturnToken, WhileError> {
+        let return_keyword = self.advance();
+        let mut return_token = ReturnToken::new(header.cloned(), SourceLocation::new(return_keyword.line, return_keyword.column));
+
+        if self.check(&SourceTokenKind::Semicolon) {
+            if header.is_some_and(|header| header.return_type != TypeToken::Void) {
+                return Err(self.error_at(&return_keyword, ErrorKind::InvalidReturn, "The method is not returning the expected value".to_string()));
+            }
+
+            self.advance();
+            return Ok(return_token);
+        }
+
+        return_token.return_value = Some(self.assignable("return")?);
+
+        if self.at_assignable() {
+            return Err(self.error_at(&return_keyword, ErrorKind::InvalidReturn, "Too many returning variables".to_string()));
+        }
+
+        self.expect_semicolon()?;
+
+        return Ok(return_token);
+    }
+
+    /// Parses an assignable, `after` names what precedes it for the error message.
+    fn assignable(&mut self, after: &str) -> Result<AssignableToken, WhileError> {
+        if !self.at_assignable() {
+            return Err(self.error_at_current(ErrorKind::ExpectedAssignable, format!("Expected an assignable after {}, but found: \"{}\"", after, self.peek().kind)));
+        }
+
+        return self.parse_assignable();
+    }
+
+    pub fn parse_assignable(&mut self) -> Result<AssignableToken, WhileError> {
+        let mut assignable = self.term()?;
+
+        while let Some(operator) = self.peek_arithmetic_operator().filter(|operator| operator.precedence() == 0) {
+            self.advance();
+            let rhs = self.operand_after(operator, Parser::term)?;
+            assignable = AssignableToken::Expression { value: ExpressionToken::new(assignable, operator, rhs) };
+        }
+
+        return Ok(assignable);
+    }
+
+    fn term(&mut self) -> Result<AssignableToken, WhileError> {
+        let mut assignable = self.power()?;
+
+        while let Some(operator) = self.peek_arithmetic_operator().filter(|operator| operator.precedence() == 1) {
+            self.advance();
+            let rhs = self.operand_after(operator, Parser::power)?;
+            assignable = AssignableToken::Expression { value: ExpressionToken::new(assignable, operator, rhs) };
+        }
+
+        return Ok(assignable);
+    }
+
+    fn power(&mut self) -> Result<AssignableToken, WhileError> {
+        let base = self.operand()?;
+
+        if !self.check(&SourceTokenKind::Caret) {
+            return Ok(base);
+        }
+        self.advance();
+
+        let exponent = self.operand_after(ArithmeticOperator::Pow, Parser::power)?;
+
+        return Ok(AssignableToken::Expression { value: ExpressionToken::new(base, ArithmeticOperator::Pow, exponent) });
+    }
+
+    /// Parses the right operand of `operator` with `rule`.
+    fn operand_after(&mut self, operator: ArithmeticOperator, rule: fn(&mut Parser) -> Result<AssignableToken, WhileError>) -> Result<AssignableToken, WhileError> {
+        if !self.at_assignable() {
+            return Err(self.error_at_current(ErrorKind::ExpectedAssignable, format!("Expected an assignable after \"{}\", but found: \"{}\"", operator, self.peek().kind)));
+        }
+
+        return rule(self);
+    }
+
+    fn peek_arithmetic_operator(&self) -> Option<ArithmeticOperator> {
+        return match self.peek().kind {
+            SourceTokenKind::Plus => Some(ArithmeticOperator::Add),
+            SourceTokenKind::Minus => Some(ArithmeticOperator::Sub),
+            SourceTokenKind::Star => Some(ArithmeticOperator::Mul),
+            SourceTokenKind::Slash => Some(ArithmeticOperator::Div),
+            SourceTokenKind::Percent => Some(ArithmeticOperator::Rem),
+            SourceTokenKind::Caret => Some(ArithmeticOperator::Pow),
+            _ => None
+        };
+    }
+
+    fn operand(&mut self) -> Result<AssignableToken, WhileError> {
+        if self.check(&SourceTokenKind::LeftParen) {
+            self.advance();
+            let assignable = self.assignable("\"(\"")?;
+
+            if !self.check(&SourceTokenKind::RightParen) {
+                return Err(self.error_after_previous(ErrorKind::UnbalancedParentheses, "Expected ')' at expression".to_string()));
+            }
+            self.advance();
+
+            return Ok(assignable);
+        }
+
+        if self.at_method_call() {
+            return Ok(AssignableToken::MethodCall { value: self.method_call()? });
+        }
+
+        if self.at_name() {
+            let name = self.name();
+            return Ok(AssignableToken::Name { value: name });
+        }
+
+        let number = self.advance();
+        return match DigitToken::parse(&number.kind.to_string()) {
+            Some(value) => Ok(AssignableToken::Digit { value }),
+            None => Err(self.error_at(&number, ErrorKind::UnexpectedToken, format!("Expected a number, but found: {}", number.kind)))
+        };
+    }
+
+    pub fn method_call(&mut self) -> Result<MethodCallToken, WhileError> {
+        let location = self.location();
+        let name = self.name();
+        self.advance();
+
+        let mut parameters = Vec::new();
+
+        if !self.check(&SourceTokenKind::RightParen) {
+            loop {
+                match &self.peek().kind {
+                    SourceTokenKind::Comma | SourceTokenKind::RightParen => return Err(self.error_at_current(ErrorKind::EmptyParameter, "Parameter can't be empty".to_string())),
+                    SourceTokenKind::Semicolon | SourceTokenKind::End => return Err(self.error_after_previous(ErrorKind::UnbalancedParentheses, "Expected ')' at method call".to_string())),
+                    _ => parameters.push(self.assignable("'(' or ','")?)
+                }
+
+                if !self.check(&SourceTokenKind::Comma) {
+                    break;
+                }
+                self.advance();
+            }
+        }
+
+        if !self.check(&SourceTokenKind::RightParen) {
+            return Err(self.error_after_previous(ErrorKind::UnbalancedParentheses, "Expected ')' at method call".to_string()));
+        }
+        self.advance();
+
+        return Ok(MethodCallToken {
+            name,
+            parameters,
+            location
+        });
+    }
+
+    fn expect_semicolon(&mut self) -> Result<(), WhileError> {
+        if !self.check(&SourceTokenKind::Semicolon) {
+            return Err(self.error_after_previous(ErrorKind::ExpectedSemicolon, format!("Expected ';', but found: {}", self.peek().kind)));
+        }
+
+        self.advance();
+        return Ok(());
+    }
+
+    /// Skips the rest of an erroneous statement: up to and including the next ';', or up to a '#',
+    /// a block keyword, a return or a method header, on whichever line they follow. At least one
+    /// token is skipped.
+    fn synchronize(&mut self, start: usize) {
+        if self.position == start {
+            self.advance();
+        }
+
+        loop {
+            if matches!(self.peek().kind, SourceTokenKind::End | SourceTokenKind::Hash) || self.at_statement_keyword() || self.at_method_header() {
+                return;
+            }
+
+            if self.advance().kind == SourceTokenKind::Semicolon {
+                return;
+            }
+        }
+    }
+
+    fn at_statement_keyword(&self) -> bool {
+        return self.at_while() || self.at_loop() || self.at_if() || self.at_return();
+    }
+
+    /// Skips the remaining tokens of the code line at `index`.
+    fn skip_line(&mut self, index: usize) {
+        while self.peek().index == index && !self.check(&SourceTokenKind::End) {
+            self.advance();
+        }
+    }
+
+    /// The location of the current token, where the statement that is parsed next starts.
+    fn location(&self) -> SourceLocation {
+        return SourceLocation::new(self.peek().line, self.peek().column);
+    }
+
+    fn at_name(&self) -> bool {
+        return matches!(self.peek().kind, SourceTokenKind::Name(_));
+    }
+
+    /// Consumes the name at the current position.
+    fn name(&mut self) -> NameToken {
+        return NameToken::new(&self.advance().kind.to_string());
+    }
+
+    fn check(&self, kind: &SourceTokenKind) -> bool {
+        return self.peek().kind == *kind;
+    }
+
+    fn peek(&self) -> &SourceToken {
+        return self.peek_at(0);
+    }
+
+    fn peek_at(&self, offset: usize) -> &SourceToken {
+        let index = (self.position + offset).min(self.tokens.len() - 1);
+        return &self.tokens[index];
+    }
+
+    fn advance(&mut self) -> SourceToken {
+        let token = self.peek().clone();
+
+        if self.position < self.tokens.len() - 1 {
+            self.position += 1;
+        }
+
+        return token;
+    }
+
+    fn error_at(&self, token: &SourceToken, kind: ErrorKind, message: String) -> WhileError {
+        return match self.code_lines.get(token.index) {
+            Some(code_line) => WhileError::at_column(kind, message, code_line, token.column),
+            None => WhileError::new(kind, message, token.line, token.column)
+        };
+    }
+
+    fn error_at_current(&self, kind: ErrorKind, message: String) -> WhileError {
+        return self.error_at(self.peek(), kind, message);
+    }
+
+    /// Reports a missing token right after the previous token, which is usually where it belongs.
+    fn error_after_previous(&self, kind: ErrorKind, message: String) -> WhileError {
+        if self.position == 0 {
+            return self.error_at_current(kind, message);
+        }
+
+        let previous = &self.tokens[self.position - 1];
+        let location = SourceToken {
+            column: previous.column + previous.width,
+            ..previous.clone()
+        };
+
+        return self.error_at(&location, kind, message);
+    }
+}
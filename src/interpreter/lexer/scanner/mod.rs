@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod scanner;
+mod source_token;
+
+pub use scanner::Scanner;
+pub use source_token::{Keyword, SourceToken, SourceTokenKind};
@@ -0,0 +1,138 @@
+use crate::interpreter::lexer::scanner::{Keyword, SourceToken, SourceTokenKind};
+use crate::interpreter::models::CodeLine;
+
+/// Splits the code lines into tokens. Whitespace and `//` comments only separate tokens,
+/// everything that isn't part of the language becomes an `Invalid` token for the parser to report.
+pub struct Scanner;
+
+impl Scanner {
+    pub fn scan(code_lines: &[CodeLine]) -> Vec<SourceToken> {
+        let mut tokens = Vec::new();
+
+        for (index, code_line) in code_lines.iter().enumerate() {
+            Scanner::scan_line(code_line, index, &mut tokens);
+        }
+
+        let (index, line, column) = match code_lines.last() {
+            Some(code_line) => (code_lines.len() - 1, code_line.line_number, code_line.line.chars().count() as u32 + 1),
+            None => (0, 0, 0)
+        };
+
+        tokens.push(SourceToken { kind: SourceTokenKind::End, index, line, column, width: 0 });
+
+        return tokens;
+    }
+
+    /// Scans a single code line, without the `End` token.
+    pub fn scan_code_line(code_line: &CodeLine) -> Vec<SourceToken> {
+        let mut tokens = Vec::new();
+        Scanner::scan_line(code_line, 0, &mut tokens);
+
+        return tokens;
+    }
+
+    fn scan_line(code_line: &CodeLine, index: usize, tokens: &mut Vec<SourceToken>) {
+        let chars = code_line.line.chars().collect::<Vec<char>>();
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+            let start = i;
+
+            if c.is_whitespace() {
+                i += 1;
+                continue;
+            }
+
+            if c == '/' && chars.get(i + 1) == Some(&'/') {
+                break;
+            }
+
+            let next = chars.get(i + 1).copied();
+
+            let kind = match c {
+                '(' => SourceTokenKind::LeftParen,
+                ')' => SourceTokenKind::RightParen,
+                ',' => SourceTokenKind::Comma,
+                ':' => SourceTokenKind::Colon,
+                ';' => SourceTokenKind::Semicolon,
+                '#' => SourceTokenKind::Hash,
+                '{' => SourceTokenKind::LeftBrace,
+                '}' => SourceTokenKind::RightBrace,
+                '=' if next == Some('=') => SourceTokenKind::Equal,
+                '=' => SourceTokenKind::Assign,
+                '<' if next == Some('=') => SourceTokenKind::LessEqual,
+                '<' => SourceTokenKind::Less,
+                '>' if next == Some('=') => SourceTokenKind::GreaterEqual,
+                '>' => SourceTokenKind::Greater,
+                '+' if next == Some('=') => SourceTokenKind::AddAssign,
+                '-' if next == Some('=') => SourceTokenKind::SubAssign,
+                '*' if next == Some('=') => SourceTokenKind::MulAssign,
+                '/' if next == Some('=') => SourceTokenKind::DivAssign,
+                '%' if next == Some('=') => SourceTokenKind::RemAssign,
+                '^' if next == Some('=') => SourceTokenKind::PowAssign,
+                '!' if next == Some('=') => SourceTokenKind::NotEqual,
+                '!' => SourceTokenKind::Bang,
+                '+' => SourceTokenKind::Plus,
+                '-' => SourceTokenKind::Minus,
+                '*' => SourceTokenKind::Star,
+                '/' => SourceTokenKind::Slash,
+                '%' => SourceTokenKind::Percent,
+                '^' => SourceTokenKind::Caret,
+                '$' if next.is_some_and(Scanner::is_word_start) => {
+                    while i + 1 < chars.len() && Scanner::is_word_char(chars[i + 1]) {
+                        i += 1;
+                    }
+
+                    SourceTokenKind::Placeholder(chars[start + 1..=i].iter().collect::<String>())
+                }
+                c if Scanner::is_word_char(c) => {
+                    while i + 1 < chars.len() && Scanner::is_word_char(chars[i + 1]) {
+                        i += 1;
+                    }
+
+                    Scanner::analyse_word(&chars[start..=i].iter().collect::<String>())
+                }
+                c => SourceTokenKind::Invalid(c.to_string())
+            };
+
+            if kind.is_two_chars() {
+                i += 1;
+            }
+
+            i += 1;
+
+            tokens.push(SourceToken {
+                kind,
+                index,
+                line: code_line.line_number,
+                column: (start + 1) as u32,
+                width: (i - start) as u32
+            });
+        }
+    }
+
+    fn is_word_start(c: char) -> bool {
+        return c.is_ascii_alphabetic() || c == '_';
+    }
+
+    fn is_word_char(c: char) -> bool {
+        return c.is_ascii_alphanumeric() || c == '_';
+    }
+
+    fn analyse_word(word: &str) -> SourceTokenKind {
+        if let Some(keyword) = Keyword::analyse(word) {
+            return SourceTokenKind::Keyword(keyword);
+        }
+
+        if word.chars().all(|c| c.is_ascii_digit()) {
+            return SourceTokenKind::Number(word.to_string());
+        }
+
+        if word.starts_with(|c: char| c.is_ascii_digit()) {
+            return SourceTokenKind::Invalid(word.to_string());
+        }
+
+        return SourceTokenKind::Name(word.to_string());
+    }
+}
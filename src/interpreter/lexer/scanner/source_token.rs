@@ -0,0 +1,168 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Keyword {
+    While,
+    Loop,
+    If,
+    Else,
+    Return,
+    Num,
+    Void,
+    Writeln,
+    And,
+    Or,
+    Not,
+    Macro,
+    Break,
+    Continue
+}
+
+impl Display for Keyword {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Keyword::While => write!(f, "while"),
+            Keyword::Loop => write!(f, "loop"),
+            Keyword::If => write!(f, "if"),
+            Keyword::Else => write!(f, "else"),
+            Keyword::Return => write!(f, "return"),
+            Keyword::Num => write!(f, "num"),
+            Keyword::Void => write!(f, "void"),
+            Keyword::Writeln => write!(f, "writeln"),
+            Keyword::And => write!(f, "and"),
+            Keyword::Or => write!(f, "or"),
+            Keyword::Not => write!(f, "not"),
+            Keyword::Macro => write!(f, "macro"),
+            Keyword::Break => write!(f, "break"),
+            Keyword::Continue => write!(f, "continue")
+        }
+    }
+}
+
+impl Keyword {
+    pub fn analyse(word: &str) -> Option<Keyword> {
+        return match word {
+            "while" => Some(Keyword::While),
+            "loop" => Some(Keyword::Loop),
+            "if" => Some(Keyword::If),
+            "else" => Some(Keyword::Else),
+            "return" => Some(Keyword::Return),
+            "num" => Some(Keyword::Num),
+            "void" => Some(Keyword::Void),
+            "writeln" => Some(Keyword::Writeln),
+            "and" => Some(Keyword::And),
+            "or" => Some(Keyword::Or),
+            "not" => Some(Keyword::Not),
+            "macro" => Some(Keyword::Macro),
+            "break" => Some(Keyword::Break),
+            "continue" => Some(Keyword::Continue),
+            _ => None
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SourceTokenKind {
+    Name(String),
+    /// `$name` in the pattern and the body of a macro, the value is the name without the `$`.
+    Placeholder(String),
+    Number(String),
+    Keyword(Keyword),
+    Assign,
+    AddAssign,
+    SubAssign,
+    MulAssign,
+    DivAssign,
+    RemAssign,
+    PowAssign,
+    NotEqual,
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    Caret,
+    Equal,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    LeftParen,
+    RightParen,
+    Comma,
+    Colon,
+    Semicolon,
+    Hash,
+    Bang,
+    LeftBrace,
+    RightBrace,
+    /// Characters that don't form a token of the language, e.g. `5a` or `.`.
+    Invalid(String),
+    End
+}
+
+impl Display for SourceTokenKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SourceTokenKind::Name(value) => write!(f, "{}", value),
+            SourceTokenKind::Placeholder(value) => write!(f, "${}", value),
+            SourceTokenKind::Number(value) => write!(f, "{}", value),
+            SourceTokenKind::Keyword(keyword) => write!(f, "{}", keyword),
+            SourceTokenKind::Assign => write!(f, "="),
+            SourceTokenKind::AddAssign => write!(f, "+="),
+            SourceTokenKind::SubAssign => write!(f, "-="),
+            SourceTokenKind::MulAssign => write!(f, "*="),
+            SourceTokenKind::DivAssign => write!(f, "/="),
+            SourceTokenKind::RemAssign => write!(f, "%="),
+            SourceTokenKind::PowAssign => write!(f, "^="),
+            SourceTokenKind::NotEqual => write!(f, "!="),
+            SourceTokenKind::Plus => write!(f, "+"),
+            SourceTokenKind::Minus => write!(f, "-"),
+            SourceTokenKind::Star => write!(f, "*"),
+            SourceTokenKind::Slash => write!(f, "/"),
+            SourceTokenKind::Percent => write!(f, "%"),
+            SourceTokenKind::Caret => write!(f, "^"),
+            SourceTokenKind::Equal => write!(f, "=="),
+            SourceTokenKind::Less => write!(f, "<"),
+            SourceTokenKind::LessEqual => write!(f, "<="),
+            SourceTokenKind::Greater => write!(f, ">"),
+            SourceTokenKind::GreaterEqual => write!(f, ">="),
+            SourceTokenKind::LeftParen => write!(f, "("),
+            SourceTokenKind::RightParen => write!(f, ")"),
+            SourceTokenKind::Comma => write!(f, ","),
+            SourceTokenKind::Colon => write!(f, ":"),
+            SourceTokenKind::Semicolon => write!(f, ";"),
+            SourceTokenKind::Hash => write!(f, "#"),
+            SourceTokenKind::Bang => write!(f, "!"),
+            SourceTokenKind::LeftBrace => write!(f, "{{"),
+            SourceTokenKind::RightBrace => write!(f, "}}"),
+            SourceTokenKind::Invalid(value) => write!(f, "{}", value),
+            SourceTokenKind::End => write!(f, "end of input")
+        }
+    }
+}
+
+impl SourceTokenKind {
+    pub fn is_two_chars(&self) -> bool {
+        return matches!(self, SourceTokenKind::AddAssign | SourceTokenKind::SubAssign | SourceTokenKind::MulAssign
+            | SourceTokenKind::DivAssign | SourceTokenKind::RemAssign | SourceTokenKind::PowAssign | SourceTokenKind::NotEqual
+            | SourceTokenKind::Equal | SourceTokenKind::LessEqual | SourceTokenKind::GreaterEqual);
+    }
+}
+
+/// A token of the source code together with its location. `index` is the position of its
+/// code line in the scanned lines, `line` and `column` are the location in the source file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SourceToken {
+    pub kind: SourceTokenKind,
+    pub index: usize,
+    pub line: u32,
+    pub column: u32,
+    pub width: u32
+}
+
+impl Display for SourceToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{} {}", self.line, self.column, self.kind)
+    }
+}
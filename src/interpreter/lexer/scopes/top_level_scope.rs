@@ -1,8 +1,8 @@
-use crate::interpreter::models::CodeLine;
 use crate::interpreter::lexer::methods::MethodToken;
-use crate::interpreter::lexer::models::{Stackable, Token};
-use crate::interpreter::utils::logging::{Logger, TreeViewElement};
+use crate::interpreter::lexer::models::Stackable;
+use crate::interpreter::utils::logging::{Logger, SourceViewElement, TreeViewElement};
 
+#[derive(Clone)]
 pub struct TopLevelScope {
     logger: Logger,
     pub stack: Vec<Stackable>,
@@ -18,10 +18,6 @@ impl TopLevelScope {
         }
     }
 
-    pub fn parse(&self, code_line: &CodeLine) -> Option<Token> {
-        return Token::parse(code_line);
-    }
-
     pub fn print(&self) {
         let lines = self.to_tree_view();
         for line in lines {
@@ -50,22 +46,35 @@ impl TreeViewElement for TopLevelScope {
         for stackable in &self.stack {
             let stackable_lines = stackable.to_tree_view();
             let stackable_lines_count = stackable_lines.len();
-            let mut counter = 0;
 
-            for stackable_line in stackable_lines {
+            for (counter, stackable_line) in stackable_lines.iter().enumerate() {
                 if stackable_lines_count == 1 {
                     lines.push(format!("│  ├── {}", stackable_line));
+                } else if counter == 0 {
+                    lines.push(format!("│  {}", stackable_line));
                 } else {
-                    if counter == 0 {
-                        lines.push(format!("│  {}", stackable_line));
-                    } else {
-                        lines.push(format!("│  │{}", stackable_line));
-                    }
+                    lines.push(format!("│  │{}", stackable_line));
                 }
-
-                counter += 1;
             }
         }
         return lines;
     }
-}
\ No newline at end of file
+}
+
+impl SourceViewElement for TopLevelScope {
+    /// The methods come first, each followed by an empty line, then the top level statements.
+    fn to_source(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        for method in &self.methods {
+            lines.extend(method.to_source());
+            lines.push(String::new());
+        }
+
+        for stackable in &self.stack {
+            lines.extend(stackable.to_source());
+        }
+
+        return lines;
+    }
+}
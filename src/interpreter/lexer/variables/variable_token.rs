@@ -1,78 +1,55 @@
 use std::fmt::{Display, Formatter};
-use crate::interpreter::executor_states::RunTime;
-use crate::interpreter::models::CodeLine;
+use crate::interpreter::errors::WhileError;
+use crate::interpreter::models::{CodeLine, SourceLocation};
 use crate::interpreter::lexer::assignables::NameToken;
 use crate::interpreter::lexer::models::AssignableToken;
-use crate::interpreter::utils::interpreter_watcher::pseudo_throw;
-use crate::interpreter::utils::logging::TreeViewElement;
+use crate::interpreter::lexer::parser::Parser;
+use crate::interpreter::utils::logging::{SourceViewElement, TreeViewElement};
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct VariableToken {
     pub name: NameToken,
-    pub assignment: AssignableToken
+    pub assignment: AssignableToken,
+    pub location: SourceLocation
 }
 
 impl Display for VariableToken {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Variable: {} = {}", self.name.value, self.assignment.evaluate())
+        write!(f, "Variable: {} = {}", self.name.value, self.assignment)
     }
 }
 
 impl TreeViewElement for VariableToken {
     fn to_tree_view(&self) -> Vec<String> {
-        if RunTime::initialized() {
-            vec![format!("Variable token: {{name: {} = {}\tAssignment: {}}}", self.name.value, self.assignment.evaluate(), self.assignment.to_tree_view()[0])]
-        } else {
-            vec![format!("Variable token: {{name: {}, Assignment: {}}}", self.name.value, self.assignment.to_tree_view()[0])]
-        }
+        vec![format!("Variable token: {{name: {}, Assignment: {}}}", self.name.value, self.assignment.to_tree_view()[0])]
     }
 }
 
 impl VariableToken {
-    pub fn get_assignable_mut(&mut self) -> &mut AssignableToken {
-        &mut self.assignment
-    }
-
-    pub fn new(name: NameToken, assignment: AssignableToken) -> Self {
+    pub fn new(name: NameToken, assignment: AssignableToken, location: SourceLocation) -> Self {
         VariableToken {
             name,
-            assignment
+            assignment,
+            location
         }
     }
 
+    pub fn parse(code_line: &CodeLine) -> Result<Option<VariableToken>, WhileError> {
+        let mut parser = Parser::new(std::slice::from_ref(code_line));
 
-    pub fn set_assignable_token(&mut self, assignable_token: AssignableToken) {
-        self.assignment = assignable_token;
-    }
-
-    pub fn parse(code_line: &CodeLine) -> Option<VariableToken> {
-        let segments = code_line.line.split(&[' ', ';'][..])
-            .filter(|p| !p.is_empty())
-            .collect::<Vec<&str>>();
-
-        let name_token = NameToken::parse(segments[0]);
-        if let None = name_token {
-            return None;
+        if !parser.at_variable() {
+            return Ok(None);
         }
 
-        if  segments[1] != "=" {
-            return None;
-        }
+        let variable_token = parser.variable()?;
+        parser.expect_end()?;
 
-        let sub_string = &segments[2..].join("");
-
-        let assignment_token = AssignableToken::parse(&CodeLine::new_from_line(sub_string));
-        if !code_line.line.ends_with(";") {
-            pseudo_throw(format!("Expected ';' at end of line: {}", code_line.line));
-            return None;
-        }
+        return Ok(Some(variable_token));
+    }
+}
 
-        if assignment_token.is_none() {
-            pseudo_throw(format!("AAAAH, at line: {}", code_line.line))
-        }
-        return Some(VariableToken {
-            name: name_token.unwrap(),
-            assignment: assignment_token.unwrap()
-        });
+impl SourceViewElement for VariableToken {
+    fn to_source(&self) -> Vec<String> {
+        return vec![format!("{} = {};", self.name.value, self.assignment.to_source()[0])];
     }
-}
\ No newline at end of file
+}
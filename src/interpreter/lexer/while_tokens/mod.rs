@@ -1,7 +1,5 @@
 mod while_token;
-mod while_escape_token;
 mod while_header_token;
 
 pub use while_header_token::WhileHeaderToken;
-pub use while_token::WhileToken;
-pub use while_escape_token::WhileEscapeToken;
\ No newline at end of file
+pub use while_token::WhileToken;
\ No newline at end of file
@@ -1,69 +1,57 @@
 use std::fmt::{Display, Formatter};
+use crate::interpreter::errors::WhileError;
 use crate::interpreter::models::CodeLine;
 use crate::interpreter::lexer::assignables::NameToken;
-use crate::interpreter::utils::interpreter_watcher::pseudo_throw;
-use crate::interpreter::utils::logging::TreeViewElement;
+use crate::interpreter::lexer::conditions::ConditionToken;
+use crate::interpreter::lexer::parser::Parser;
+use crate::interpreter::utils::logging::{SourceViewElement, TreeViewElement};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct WhileHeaderToken {
-    // x != 0
-    // The while language only can use x != 0, so no need for real boolean algebra
-    pub against_zero_variable: Option<NameToken>
+    // x != 0 in classic While, any condition in the extended dialect
+    pub condition: ConditionToken
 }
 
 impl Display for WhileHeaderToken {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self.against_zero_variable {
-            Some(ref variable) => write!(f, "while {} != 0", variable),
-            None => write!(f, "while")
-        }
+        write!(f, "while {}", self.condition)
     }
 }
 
 impl WhileHeaderToken {
-    pub fn new(against_zero_variable: Option<NameToken>) -> WhileHeaderToken {
+    pub fn new(condition: ConditionToken) -> WhileHeaderToken {
         WhileHeaderToken {
-            against_zero_variable
+            condition
         }
     }
 
-    pub fn parse(code_line: &CodeLine) -> Option<Self> {
-        let split = code_line.line.split(&[' ', ':'][..])
-            .filter(|p| !p.is_empty() && !p.trim().is_empty())
-            .collect::<Vec<&str>>();
-
-        if split[0] != "while" {
-            return None;
-        }
-
-        let name_token = NameToken::parse(split[1]);
-
-        if name_token.is_none() {
-            pseudo_throw(format!("Expected a name at line: {}", code_line.line));
-            return None;
-        }
+    /// The classic header `while name != 0`.
+    pub fn against_zero(name: NameToken) -> WhileHeaderToken {
+        return WhileHeaderToken::new(ConditionToken::against_zero(name));
+    }
 
-        if split[2] != "!=" {
-            pseudo_throw(format!("Expected a != at line: {}", code_line.line));
-            return None;
-        }
+    pub fn parse(code_line: &CodeLine) -> Result<Option<Self>, WhileError> {
+        let mut parser = Parser::new(std::slice::from_ref(code_line));
 
-        if split[3] != "0" {
-            pseudo_throw(format!("Expected a \"0\" as comparer at line: {}", code_line.line));
-            return None;
+        if !parser.at_while() {
+            return Ok(None);
         }
 
-        if !code_line.line.ends_with(":") {
-            pseudo_throw(format!("Expected a \":\" at line: {}", code_line.line));
-            return None;
-        }
+        let while_header_token = parser.while_header()?;
+        parser.expect_end()?;
 
-        Some(WhileHeaderToken::new(name_token))
+        return Ok(Some(while_header_token));
     }
 }
 
 impl TreeViewElement for WhileHeaderToken {
     fn to_tree_view(&self) -> Vec<String> {
-        vec![format!("Header: {{while target: {}}}", self.against_zero_variable.as_ref().unwrap().to_tree_view()[0])]
+        vec![format!("Header: {{while condition: {}}}", self.condition.to_tree_view()[0])]
+    }
+}
+
+impl SourceViewElement for WhileHeaderToken {
+    fn to_source(&self) -> Vec<String> {
+        return vec![format!("while {}:", self.condition.to_source()[0])];
     }
 }
@@ -1,39 +1,48 @@
 use std::fmt::{Display, Formatter};
+use crate::interpreter::errors::WhileError;
+use crate::interpreter::executor_states::ExecutionContext;
 use crate::interpreter::lexer::methods::MethodHeaderToken;
-use crate::interpreter::models::{BodyExecutor, CodeLine};
-use crate::interpreter::lexer::models::Token;
+use crate::interpreter::models::{BodyExecutor, ControlFlow, SourceLocation};
 use crate::interpreter::lexer::scopes::InnerBodyScope;
 use crate::interpreter::lexer::while_tokens::WhileHeaderToken;
-use crate::interpreter::utils::logging::TreeViewElement;
+use crate::interpreter::utils::logging::{SourceViewElement, TreeViewElement};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct WhileToken {
     pub header_token: WhileHeaderToken,
     pub scope: Option<InnerBodyScope>,
-    pub escape_token_found: bool,
     pub method_header_token: Option<MethodHeaderToken>,
-
-    code_lines: Vec<CodeLine>
+    pub location: SourceLocation
 }
 
 impl WhileToken {
-    pub fn evaluate(&self) -> Option<u32> {
-        if let Some(against_zero_variable) = &self.header_token.against_zero_variable {
-            if let Some(scope) = &self.scope {
-
-                let body_executor: BodyExecutor = BodyExecutor {
-                    scope: scope.stack.clone()
-                };
-
-                while against_zero_variable.evaluate() != 0 {
-                    if let Some(value) = body_executor.execute() {
-                        return Some(value);
-                    }
+    /// Runs the body while the condition holds. A break ends the loop, a return inside the body
+    /// ends the enclosing method, so its value is passed on.
+    pub fn evaluate(&self, context: &mut ExecutionContext) -> Result<ControlFlow, WhileError> {
+        if let Some(scope) = &self.scope {
+
+            let body_executor: BodyExecutor = BodyExecutor {
+                scope: scope.stack.clone()
+            };
+
+            loop {
+                context.location = self.location;
+                let condition = self.header_token.condition.evaluate(context)?;
+                context.trace(|_| format!("while {}:  => {}", self.header_token.condition.to_source()[0], condition));
+
+                if !condition {
+                    break;
+                }
+
+                match context.traced_block(|context| body_executor.execute(context))? {
+                    ControlFlow::Break => break,
+                    ControlFlow::Return(value) => return Ok(ControlFlow::Return(value)),
+                    ControlFlow::Normal | ControlFlow::Continue => {}
                 }
             }
         }
-        
-        None
+
+        Ok(ControlFlow::Normal)
     }
 }
 
@@ -44,22 +53,13 @@ impl Display for WhileToken {
 }
 
 impl WhileToken {
-    pub fn new(header_token: WhileHeaderToken, method_header_token: Option<MethodHeaderToken>, code_lines: Vec<CodeLine>) -> Self {
+    pub fn new(header_token: WhileHeaderToken, method_header_token: Option<MethodHeaderToken>, location: SourceLocation) -> Self {
         WhileToken {
             header_token,
             scope: None,
             method_header_token,
-            escape_token_found: false,
-            code_lines
-        }
-    }
-
-    pub fn parse(&mut self, code_line: &CodeLine) -> Option<Token> {
-        if self.scope.is_none() {
-            self.scope = Some(InnerBodyScope::new(self.method_header_token.clone(), self.code_lines.clone()));
+            location
         }
-
-        return self.scope.as_mut().unwrap().parse(code_line);
     }
 }
 
@@ -70,8 +70,8 @@ impl TreeViewElement for WhileToken {
         lines.push(format!("   ├── {}", self.header_token.to_tree_view()[0]));
         lines.push(        "   └── Scope:".to_string());
 
-        if self.scope.is_some() {
-            let temp_lines = self.scope.as_ref().unwrap().to_tree_view();
+        if let Some(scope) = &self.scope {
+            let temp_lines = scope.to_tree_view();
 
             for temp_line in temp_lines {
                 lines.push(format!("      {}", temp_line));
@@ -80,4 +80,18 @@ impl TreeViewElement for WhileToken {
 
         return lines;
     }
-}
\ No newline at end of file
+}
+
+impl SourceViewElement for WhileToken {
+    fn to_source(&self) -> Vec<String> {
+        let mut lines = self.header_token.to_source();
+
+        if let Some(scope) = &self.scope {
+            lines.extend(scope.to_source());
+        }
+
+        lines.push("#".to_string());
+
+        return lines;
+    }
+}
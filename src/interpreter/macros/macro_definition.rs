@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use crate::interpreter::errors::{ErrorKind, WhileError};
+use crate::interpreter::lexer::scanner::{Keyword, Scanner, SourceToken, SourceTokenKind};
+use crate::interpreter::models::CodeLine;
+
+/// A macro defined by
+///
+/// ```text
+/// macro! $variable *= $factor; = {
+///     ...
+/// }
+/// ```
+///
+/// The pattern is a single statement. Names starting with `$` are placeholders, every other token
+/// of the pattern must appear literally. Each placeholder must be followed by a literal token.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MacroDefinition {
+    pub pattern: Vec<SourceTokenKind>,
+    pub body: Vec<CodeLine>,
+    pub line: u32
+}
+
+impl Display for MacroDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let pattern = self.pattern.iter().map(|kind| kind.to_string()).collect::<Vec<String>>();
+        write!(f, "{}", pattern.join(" "))
+    }
+}
+
+impl MacroDefinition {
+    pub fn is_placeholder(kind: &SourceTokenKind) -> bool {
+        return matches!(kind, SourceTokenKind::Placeholder(_));
+    }
+
+    pub fn at_definition(tokens: &[SourceToken]) -> bool {
+        return tokens.first().is_some_and(|token| token.kind == SourceTokenKind::Keyword(Keyword::Macro));
+    }
+
+    /// Parses the definition starting at `code_lines[index]`. Returns the definition and the index
+    /// of the first line after its closing `}`.
+    pub fn parse(code_lines: &[CodeLine], index: usize) -> Result<(MacroDefinition, usize), WhileError> {
+        let code_line = &code_lines[index];
+        let tokens = Scanner::scan_code_line(code_line);
+
+        if tokens.get(1).map(|token| &token.kind) != Some(&SourceTokenKind::Bang) {
+            let column = tokens.get(1).map_or(code_line.column, |token| token.column);
+            return Err(WhileError::at_column(ErrorKind::InvalidMacro, "Expected \"!\" after macro".to_string(), code_line, column));
+        }
+
+        let kinds = tokens.iter().map(|token| token.kind.clone()).collect::<Vec<SourceTokenKind>>();
+
+        if kinds.len() < 4 || kinds[kinds.len() - 2..] != [SourceTokenKind::Assign, SourceTokenKind::LeftBrace] {
+            return Err(WhileError::at(ErrorKind::InvalidMacro, "Expected \"= {\" at the end of the macro pattern".to_string(), code_line));
+        }
+
+        let pattern = kinds[2..kinds.len() - 2].to_vec();
+        MacroDefinition::check_pattern(&pattern).map_err(|message| WhileError::at_column(ErrorKind::InvalidMacro, message, code_line, tokens[2].column))?;
+
+        let end = MacroDefinition::closing_line(code_lines, index).ok_or_else(|| {
+            WhileError::at(ErrorKind::InvalidMacro, "Missing \"}\" at the end of the macro".to_string(), code_line)
+        })?;
+
+        let body = code_lines[index + 1..end].iter()
+            .filter(|body_line| !body_line.line.trim().is_empty())
+            .cloned()
+            .collect::<Vec<CodeLine>>();
+
+        for body_line in &body {
+            for token in Scanner::scan_code_line(body_line) {
+                if MacroDefinition::is_placeholder(&token.kind) && !pattern.contains(&token.kind) {
+                    return Err(WhileError::at_column(ErrorKind::InvalidMacro, format!("Unknown placeholder {}", token.kind), body_line, token.column));
+                }
+
+                if token.kind == SourceTokenKind::Keyword(Keyword::Macro) {
+                    return Err(WhileError::at_column(ErrorKind::InvalidMacro, "Macros can't be defined inside of a macro".to_string(), body_line, token.column));
+                }
+            }
+        }
+
+        let definition = MacroDefinition {
+            pattern,
+            body,
+            line: code_line.line_number
+        };
+
+        return Ok((definition, end + 1));
+    }
+
+    /// Two patterns are the same if they only differ in the names of their placeholders.
+    pub fn same_pattern(&self, other: &MacroDefinition) -> bool {
+        return self.pattern.len() == other.pattern.len() && self.pattern.iter().zip(&other.pattern).all(|(lhs, rhs)| {
+            lhs == rhs || MacroDefinition::is_placeholder(lhs) && MacroDefinition::is_placeholder(rhs)
+        });
+    }
+
+    /// Finds the line holding only the `}` that closes the definition at `code_lines[index]`.
+    pub fn closing_line(code_lines: &[CodeLine], index: usize) -> Option<usize> {
+        return (index + 1..code_lines.len()).find(|i| code_lines[*i].line.trim() == "}");
+    }
+
+    /// Matches the pattern against the tokens from `start` on. Returns the tokens bound to each
+    /// placeholder and the index after the match. A placeholder takes every token up to its following
+    /// literal that isn't inside parentheses.
+    pub fn match_at(&self, tokens: &[SourceToken], start: usize) -> Option<(HashMap<String, Range<usize>>, usize)> {
+        let mut bindings = HashMap::new();
+        let mut position = start;
+
+        for (i, kind) in self.pattern.iter().enumerate() {
+            if !MacroDefinition::is_placeholder(kind) {
+                if tokens.get(position)?.kind != *kind {
+                    return None;
+                }
+
+                position += 1;
+                continue;
+            }
+
+            let terminator = &self.pattern[i + 1];
+            let begin = position;
+            let mut depth = 0;
+
+            loop {
+                let token = tokens.get(position)?;
+
+                match token.kind {
+                    _ if depth == 0 && token.kind == *terminator => break,
+                    SourceTokenKind::Semicolon if depth == 0 => return None,
+                    SourceTokenKind::LeftParen => depth += 1,
+                    SourceTokenKind::RightParen if depth == 0 => return None,
+                    SourceTokenKind::RightParen => depth -= 1,
+                    _ => {}
+                }
+
+                position += 1;
+            }
+
+            if position == begin {
+                return None;
+            }
+
+            bindings.insert(kind.to_string(), begin..position);
+        }
+
+        return Some((bindings, position));
+    }
+
+    fn check_pattern(pattern: &[SourceTokenKind]) -> Result<(), String> {
+        if pattern.len() < 2 {
+            return Err("The pattern of a macro can't be empty".to_string());
+        }
+
+        if pattern.last() != Some(&SourceTokenKind::Semicolon) {
+            return Err("The pattern of a macro must be a statement ending with \";\"".to_string());
+        }
+
+        for (i, kind) in pattern.iter().enumerate() {
+            if matches!(kind, SourceTokenKind::Semicolon | SourceTokenKind::Colon | SourceTokenKind::Hash) && i != pattern.len() - 1 {
+                return Err(format!("The pattern of a macro must be a single statement, but contains \"{}\"", kind));
+            }
+
+            if matches!(kind, SourceTokenKind::Bang | SourceTokenKind::LeftBrace | SourceTokenKind::RightBrace
+                | SourceTokenKind::Keyword(Keyword::Macro) | SourceTokenKind::Invalid(_)) {
+                return Err(format!("Unexpected \"{}\" in the pattern of a macro", kind));
+            }
+
+            if !MacroDefinition::is_placeholder(kind) {
+                continue;
+            }
+
+            if MacroDefinition::is_placeholder(&pattern[i + 1]) {
+                return Err(format!("The placeholders {} and {} must be separated by a token", kind, pattern[i + 1]));
+            }
+
+            if pattern[..i].contains(kind) {
+                return Err(format!("The placeholder {} is used twice", kind));
+            }
+        }
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,227 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use crate::interpreter::errors::{ErrorKind, WhileError};
+use crate::interpreter::lexer::scanner::{Scanner, SourceToken, SourceTokenKind};
+use crate::interpreter::macros::MacroDefinition;
+use crate::interpreter::models::CodeLine;
+
+/// Macros may use other macros, but an expansion nested deeper than this doesn't terminate.
+const MAX_DEPTH: usize = 32;
+
+/// Rewrites the uses of macros into core While statements before the program is parsed.
+///
+/// Every statement that matches the pattern of a macro is replaced by the body of the macro, with
+/// the placeholders replaced by the matched source. The other names of the body are temporaries
+/// of the macro and are renamed to `name_n` for the n-th expansion, with more `_` appended while the
+/// program already uses the name. Method calls and names that are part of a macro pattern keep their
+/// name.
+pub struct MacroExpander {
+    definitions: Vec<MacroDefinition>,
+    names: HashSet<String>,
+    expansions: usize
+}
+
+impl MacroExpander {
+    /// Removes the macro definitions from the code lines and expands every use. The expanded lines
+    /// keep the line number of the statement they replace.
+    pub fn expand(code_lines: &[CodeLine]) -> Result<Vec<CodeLine>, Vec<WhileError>> {
+        return MacroExpander::expand_avoiding(code_lines, &HashSet::new());
+    }
+
+    /// Like `expand`, but the temporaries are also named differently from `names`, e.g. the
+    /// variables of earlier inputs of the REPL.
+    pub fn expand_avoiding(code_lines: &[CodeLine], names: &HashSet<String>) -> Result<Vec<CodeLine>, Vec<WhileError>> {
+        let mut expander = MacroExpander {
+            definitions: Vec::new(),
+            names: names.clone(),
+            expansions: 0
+        };
+
+        let mut diagnostics = Vec::new();
+        let mut program = Vec::new();
+        let mut index = 0;
+
+        while index < code_lines.len() {
+            let tokens = Scanner::scan_code_line(&code_lines[index]);
+
+            if !MacroDefinition::at_definition(&tokens) {
+                program.push(code_lines[index].clone());
+                index += 1;
+                continue;
+            }
+
+            match MacroDefinition::parse(code_lines, index) {
+                Ok((definition, next)) => {
+                    if expander.definitions.iter().any(|other| other.same_pattern(&definition)) {
+                        let message = format!("The macro {} is already defined", definition);
+                        diagnostics.push(WhileError::at(ErrorKind::InvalidMacro, message, &code_lines[index]));
+                    }
+
+                    expander.definitions.push(definition);
+                    index = next;
+                }
+                Err(error) => {
+                    diagnostics.push(error);
+                    index = MacroDefinition::closing_line(code_lines, index).unwrap_or(index) + 1;
+                }
+            }
+        }
+
+        for code_line in code_lines {
+            expander.names.extend(Scanner::scan_code_line(code_line).into_iter().filter_map(|token| match token.kind {
+                SourceTokenKind::Name(name) => Some(name),
+                _ => None
+            }));
+        }
+
+        let mut expanded = Vec::new();
+
+        for code_line in program {
+            if let Err(error) = expander.expand_line(code_line, 0, &mut expanded) {
+                diagnostics.push(error);
+            }
+        }
+
+        if !diagnostics.is_empty() {
+            diagnostics.sort_by_key(|diagnostic| diagnostic.line);
+            return Err(diagnostics);
+        }
+
+        return Ok(expanded);
+    }
+
+    /// Expands the first use of a macro in the line, then the expanded body and the rest of the line.
+    fn expand_line(&mut self, code_line: CodeLine, depth: usize, expanded: &mut Vec<CodeLine>) -> Result<(), WhileError> {
+        let tokens = Scanner::scan_code_line(&code_line);
+
+        let found = (0..tokens.len())
+            .filter(|i| *i == 0 || matches!(tokens[i - 1].kind, SourceTokenKind::Semicolon | SourceTokenKind::Colon | SourceTokenKind::Hash))
+            .find_map(|i| self.definitions.iter().find_map(|definition| {
+                definition.match_at(&tokens, i).map(|(bindings, end)| (i, definition.clone(), bindings, end))
+            }));
+
+        let Some((start, definition, bindings, end)) = found else {
+            expanded.push(code_line);
+            return Ok(());
+        };
+
+        if depth == MAX_DEPTH {
+            let message = format!("The expansion of the macro {} doesn't end, a macro can't use itself", definition);
+            return Err(WhileError::at_column(ErrorKind::MacroExpansion, message, &code_line, tokens[start].column));
+        }
+
+        let chars = code_line.line.chars().collect::<Vec<char>>();
+        let indentation = chars.iter().take_while(|c| c.is_whitespace()).collect::<String>();
+        let begin = (tokens[start].column - 1) as usize;
+        let finish = (tokens[end - 1].column - 1 + tokens[end - 1].width) as usize;
+
+        let prefix = chars[..begin].iter().collect::<String>();
+
+        if !prefix.trim().is_empty() {
+            expanded.push(CodeLine::new(prefix.trim_end(), code_line.line_number));
+        }
+
+        for body_line in self.instantiate(&definition, &bindings, &tokens, &chars, &indentation, code_line.line_number) {
+            self.expand_line(body_line, depth + 1, expanded)?;
+        }
+
+        let suffix = chars[finish..].iter().collect::<String>();
+
+        if !suffix.trim().is_empty() {
+            let rest = CodeLine::new(&format!("{}{}", indentation, suffix.trim()), code_line.line_number);
+            self.expand_line(rest, depth, expanded)?;
+        }
+
+        return Ok(());
+    }
+
+    fn instantiate(&mut self, definition: &MacroDefinition, bindings: &HashMap<String, Range<usize>>, tokens: &[SourceToken],
+                   chars: &[char], indentation: &str, line_number: u32) -> Vec<CodeLine> {
+        self.expansions += 1;
+
+        let arguments = bindings.iter()
+            .map(|(placeholder, range)| (placeholder.clone(), MacroExpander::argument(&tokens[range.clone()], chars)))
+            .collect::<HashMap<String, String>>();
+
+        let margin = definition.body.iter().map(|body_line| body_line.column - 1).min().unwrap_or(0) as usize;
+        let mut temporaries: HashMap<String, String> = HashMap::new();
+        let mut body = Vec::new();
+
+        for body_line in &definition.body {
+            let body_tokens = Scanner::scan_code_line(body_line);
+            let mut text = body_line.line.chars().collect::<Vec<char>>();
+
+            for (i, token) in body_tokens.iter().enumerate().rev() {
+                let replacement = match &token.kind {
+                    SourceTokenKind::Placeholder(_) => arguments[&token.kind.to_string()].clone(),
+                    SourceTokenKind::Name(_) if body_tokens.get(i + 1).is_some_and(|next| next.kind == SourceTokenKind::LeftParen) => continue,
+                    SourceTokenKind::Name(_) if self.definitions.iter().any(|other| other.pattern.contains(&token.kind)) => continue,
+                    SourceTokenKind::Name(name) => match temporaries.get(name) {
+                        Some(temporary) => temporary.clone(),
+                        None => {
+                            let temporary = self.fresh_name(name);
+                            temporaries.insert(name.clone(), temporary.clone());
+                            temporary
+                        }
+                    },
+                    _ => continue
+                };
+
+                let column = (token.column - 1) as usize;
+                text.splice(column..column + token.width as usize, replacement.chars());
+            }
+
+            let line = text.iter().skip(margin).collect::<String>();
+            body.push(CodeLine::new(&format!("{}{}", indentation, line), line_number));
+        }
+
+        return body;
+    }
+
+    /// The source of the tokens bound to a placeholder. Anything but a single operand is put into
+    /// parentheses, so `$x * 2` with `a + 1` for `$x` stays `(a + 1) * 2`.
+    fn argument(tokens: &[SourceToken], chars: &[char]) -> String {
+        let first = &tokens[0];
+        let last = &tokens[tokens.len() - 1];
+        let source = chars[(first.column - 1) as usize..(last.column - 1 + last.width) as usize].iter().collect::<String>();
+
+        if tokens.len() == 1 || MacroExpander::is_call(tokens) {
+            return source;
+        }
+
+        return format!("({})", source);
+    }
+
+    fn is_call(tokens: &[SourceToken]) -> bool {
+        if !matches!(tokens[0].kind, SourceTokenKind::Name(_)) || tokens[1].kind != SourceTokenKind::LeftParen {
+            return false;
+        }
+
+        let mut depth = 0;
+
+        for (i, token) in tokens.iter().enumerate().skip(1) {
+            match token.kind {
+                SourceTokenKind::LeftParen => depth += 1,
+                SourceTokenKind::RightParen => depth -= 1,
+                _ => {}
+            }
+
+            if depth == 0 {
+                return i == tokens.len() - 1;
+            }
+        }
+
+        return false;
+    }
+
+    fn fresh_name(&mut self, name: &str) -> String {
+        let mut temporary = format!("{}_{}", name, self.expansions);
+
+        while self.names.contains(&temporary) {
+            temporary.push('_');
+        }
+
+        self.names.insert(temporary.clone());
+        return temporary;
+    }
+}
@@ -0,0 +1,5 @@
+mod macro_definition;
+mod macro_expander;
+
+pub use macro_definition::MacroDefinition;
+pub use macro_expander::MacroExpander;
@@ -2,9 +2,15 @@ mod read_helper;
 pub mod models;
 pub mod utils;
 pub mod lexer;
-pub mod constants;
+pub mod errors;
 pub mod executor_states;
+pub mod analysis;
+pub mod macros;
+pub mod desugar;
+pub mod repl;
+pub mod cli;
 
 
 pub use read_helper::read;
+pub use read_helper::split;
 pub use read_helper::normalize;
\ No newline at end of file
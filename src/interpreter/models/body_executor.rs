@@ -1,35 +1,81 @@
-use crate::interpreter::executor_states::RunTime;
+use crate::interpreter::errors::WhileError;
+use crate::interpreter::executor_states::ExecutionContext;
+use crate::interpreter::lexer::loop_control::LoopControlToken;
 use crate::interpreter::lexer::models::Stackable;
+use crate::interpreter::models::{ControlFlow, Value};
+use crate::interpreter::utils::logging::SourceViewElement;
 
 pub struct BodyExecutor {
     pub scope: Vec<Stackable>
 }
 
 impl BodyExecutor {
-    pub fn execute(&self) -> Option<u32> {
+    pub fn execute(&self, context: &mut ExecutionContext) -> Result<ControlFlow, WhileError> {
         for stackable in &self.scope {
+            if let Some(location) = stackable.location() {
+                context.location = location;
+            }
+
             match stackable {
                 Stackable::VariableToken { value } => {
-                    RunTime::get_variable_list().add_or_update(value.clone());
+                    context.assign(value)?;
+                    context.trace(|context| BodyExecutor::assigned(stackable, &value.name.value, context));
+                }
+                Stackable::OperatorToken { value } => {
+                    context.update(value)?;
+                    context.trace(|context| BodyExecutor::assigned(stackable, &value.name.value, context));
                 }
-                Stackable::AdditiveOperatorToken { value } => {
-                    RunTime::get_variable_list().update(value.clone());
+                Stackable::WritelnToken { value } => {
+                    context.trace(|_| stackable.to_source()[0].clone());
+                    value.evaluate(context)?;
                 }
                 Stackable::MethodCallToken { ref value } => {
-                    value.evaluate();
+                    context.trace(|_| stackable.to_source()[0].clone());
+                    value.evaluate(context)?;
                 }
                 Stackable::WhileToken { value } => {
-                    let option = value.evaluate();
-                    if option.is_some() {
-                        return option;
+                    let control_flow = value.evaluate(context)?;
+                    if !control_flow.is_normal() {
+                        return Ok(control_flow);
+                    }
+                }
+                Stackable::LoopToken { value } => {
+                    let control_flow = value.evaluate(context)?;
+                    if !control_flow.is_normal() {
+                        return Ok(control_flow);
+                    }
+                }
+                Stackable::IfToken { value } => {
+                    let control_flow = value.evaluate(context)?;
+                    if !control_flow.is_normal() {
+                        return Ok(control_flow);
                     }
                 }
+                Stackable::LoopControlToken { value } => {
+                    context.trace(|_| stackable.to_source()[0].clone());
+
+                    return match value {
+                        LoopControlToken::Break => Ok(ControlFlow::Break),
+                        LoopControlToken::Continue => Ok(ControlFlow::Continue)
+                    };
+                }
                 Stackable::ReturnToken { value } => {
-                    return Some(value.return_value.as_ref().unwrap().evaluate());
+                    let return_value = match &value.return_value {
+                        Some(return_value) => return_value.evaluate(context)?,
+                        None => Value::default()
+                    };
+
+                    context.trace(|_| format!("{}  => {}", stackable.to_source()[0], return_value));
+                    return Ok(ControlFlow::Return(return_value));
                 }
             }
         }
 
-        return None;
+        return Ok(ControlFlow::Normal);
+    }
+
+    /// The step of an assignment with the new value of the variable.
+    fn assigned(stackable: &Stackable, name: &str, context: &ExecutionContext) -> String {
+        return format!("{}  => {}", stackable.to_source()[0], context.environment.get(name).unwrap_or_default());
     }
 }
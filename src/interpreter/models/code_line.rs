@@ -3,7 +3,9 @@ use std::fmt::{Debug, Display, Formatter};
 #[derive(Clone, PartialEq)]
 pub struct CodeLine {
     pub line: String,
-    pub line_number: u32
+    pub line_number: u32,
+    /// 1-based column of the first non-whitespace character in the source line.
+    pub column: u32
 }
 
 impl Debug for CodeLine {
@@ -19,9 +21,12 @@ impl Display for CodeLine {
 
 impl CodeLine {
     pub fn new(line: &str, line_number: u32) -> Self {
+        let indentation = line.chars().take_while(|c| c.is_whitespace()).count();
+
         CodeLine {
             line: line.to_string(),
-            line_number
+            line_number,
+            column: (indentation + 1) as u32
         }
     }
 
@@ -0,0 +1,16 @@
+use crate::interpreter::models::Value;
+
+/// How a body finished: after its last statement, by a jump to the enclosing loop or by a return.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ControlFlow {
+    Normal,
+    Break,
+    Continue,
+    Return(Value)
+}
+
+impl ControlFlow {
+    pub fn is_normal(&self) -> bool {
+        return matches!(self, ControlFlow::Normal);
+    }
+}
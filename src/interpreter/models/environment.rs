@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use crate::interpreter::models::Value;
+
+/// The variable store of a running program.
+///
+/// Variables assigned at the top level are globals. Every method call gets its own frame for its
+/// parameters and locals, which is dropped when the method returns, so recursive calls never share
+/// variables. Inside a method:
+/// - reading a variable looks into the frame of the call first and then into the globals,
+/// - `=` always assigns a local variable, which shadows a global of the same name,
+/// - `+=` and `-=` change the local variable, or the global if there is no local one.
+///
+/// The variables of the calling method are never visible.
+#[derive(Default)]
+pub struct Environment {
+    globals: HashMap<String, Value>,
+    frames: Vec<HashMap<String, Value>>
+}
+
+impl Environment {
+    pub fn new() -> Self {
+        Environment {
+            globals: HashMap::new(),
+            frames: Vec::new()
+        }
+    }
+
+    pub fn push_frame(&mut self) {
+        self.frames.push(HashMap::new());
+    }
+
+    pub fn pop_frame(&mut self) {
+        self.frames.pop();
+    }
+
+    pub fn assign(&mut self, name: &str, value: Value) {
+        self.current_frame_mut().insert(name.to_string(), value);
+    }
+
+    pub fn get(&self, name: &str) -> Option<Value> {
+        if let Some(value) = self.frames.last().and_then(|frame| frame.get(name)) {
+            return Some(value.clone());
+        }
+
+        return self.globals.get(name).cloned();
+    }
+
+    pub fn globals(&self) -> &HashMap<String, Value> {
+        return &self.globals;
+    }
+
+    /// Returns the variable an operator like "+=" operates on.
+    pub fn get_for_update(&mut self, name: &str) -> Option<&mut Value> {
+        if let Some(frame) = self.frames.last_mut() {
+            if frame.contains_key(name) {
+                return frame.get_mut(name);
+            }
+        }
+
+        return self.globals.get_mut(name);
+    }
+
+    fn current_frame_mut(&mut self) -> &mut HashMap<String, Value> {
+        return match self.frames.last_mut() {
+            Some(frame) => frame,
+            None => &mut self.globals
+        };
+    }
+}
+
+impl Display for Environment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let variables = self.frames.last().unwrap_or(&self.globals);
+
+        if variables.is_empty() {
+            return write!(f, "[]");
+        }
+
+        let mut names = variables.keys().collect::<Vec<&String>>();
+        names.sort();
+
+        let lines = names.iter()
+            .map(|name| format!("\t{}: {}", name, variables[*name]))
+            .collect::<Vec<String>>();
+
+        write!(f, "[\n{}\n]", lines.join(",\n"))
+    }
+}
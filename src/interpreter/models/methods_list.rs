@@ -1,8 +1,10 @@
 use std::collections::HashMap;
+use std::sync::Arc;
 use crate::interpreter::lexer::methods::MethodToken;
 
+#[derive(Default)]
 pub struct MethodsList {
-    pub tokens: HashMap<String, MethodToken>
+    pub tokens: HashMap<String, Arc<MethodToken>>
 }
 
 impl MethodsList {
@@ -12,11 +14,17 @@ impl MethodsList {
         }
     }
 
-    pub fn get(&self, index :&str) -> Option<&MethodToken> {
-        return self.tokens.get(index);
+    pub fn get(&self, index :&str) -> Option<Arc<MethodToken>> {
+        return self.tokens.get(index).cloned();
     }
 
-    pub fn insert(&mut self, index: String, value: MethodToken) {
-        self.tokens.insert(index.to_string(), value);
+    /// Adds a method, unless a method with that name exists already. Returns whether it was added.
+    pub fn insert(&mut self, index: String, value: MethodToken) -> bool {
+        if self.tokens.contains_key(&index) {
+            return false;
+        }
+
+        self.tokens.insert(index, Arc::new(value));
+        return true;
     }
 }
\ No newline at end of file
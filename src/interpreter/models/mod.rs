@@ -1,9 +1,17 @@
 mod code_line;
-mod variables_list;
+mod environment;
 mod methods_list;
+mod natural;
 mod body_executor;
+mod control_flow;
+mod source_location;
+mod value;
 
 pub use methods_list::MethodsList;
-pub use variables_list::VariablesList;
+pub use environment::Environment;
 pub use code_line::CodeLine;
 pub use body_executor::BodyExecutor;
+pub use control_flow::ControlFlow;
+pub use source_location::SourceLocation;
+pub use natural::Natural;
+pub use value::Value;
@@ -0,0 +1,282 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+const BASE: u64 = 1 << 32;
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+
+/// An unbounded natural number. The digits are stored in base 2^32 with the least significant
+/// digit first and without leading zeros, so zero has no digits at all.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Natural {
+    digits: Vec<u32>
+}
+
+impl From<u32> for Natural {
+    fn from(value: u32) -> Self {
+        return Natural::from(value as u64);
+    }
+}
+
+impl From<u64> for Natural {
+    fn from(value: u64) -> Self {
+        let mut natural = Natural {
+            digits: vec![value as u32, (value >> 32) as u32]
+        };
+
+        natural.trim();
+        return natural;
+    }
+}
+
+impl From<u128> for Natural {
+    fn from(value: u128) -> Self {
+        let mut natural = Natural {
+            digits: (0..4).map(|i| (value >> (32 * i)) as u32).collect()
+        };
+
+        natural.trim();
+        return natural;
+    }
+}
+
+impl Display for Natural {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        let mut chunks = Vec::new();
+        let mut rest = self.clone();
+
+        while !rest.is_zero() {
+            let (quotient, remainder) = rest.div_small(DECIMAL_CHUNK);
+            chunks.push(remainder);
+            rest = quotient;
+        }
+
+        write!(f, "{}", chunks.last().unwrap())?;
+
+        for chunk in chunks.iter().rev().skip(1) {
+            write!(f, "{:09}", chunk)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl PartialOrd for Natural {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Natural {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return self.digits.len().cmp(&other.digits.len())
+            .then_with(|| self.digits.iter().rev().cmp(other.digits.iter().rev()));
+    }
+}
+
+impl Natural {
+    pub fn zero() -> Self {
+        return Natural::default();
+    }
+
+    pub fn is_zero(&self) -> bool {
+        return self.digits.is_empty();
+    }
+
+    /// Parses a decimal number of any length, which must only consist of the digits 0-9.
+    pub fn parse(decimal: &str) -> Option<Natural> {
+        if decimal.is_empty() || !decimal.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let mut natural = Natural::zero();
+
+        for c in decimal.chars() {
+            natural.mul_add_small(10, c.to_digit(10).unwrap());
+        }
+
+        return Some(natural);
+    }
+
+    /// Returns the value if it fits into a `u128`.
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.digits.len() > 4 {
+            return None;
+        }
+
+        return Some(self.digits.iter().rev().fold(0u128, |value, digit| value << 32 | *digit as u128));
+    }
+
+    /// Returns the lowest 64 bits, which is the value modulo 2^64.
+    pub fn low_u64(&self) -> u64 {
+        let low = *self.digits.first().unwrap_or(&0) as u64;
+        let high = *self.digits.get(1).unwrap_or(&0) as u64;
+
+        return high << 32 | low;
+    }
+
+    pub fn add(&self, rhs: &Natural) -> Natural {
+        let mut digits = Vec::with_capacity(self.digits.len().max(rhs.digits.len()) + 1);
+        let mut carry = 0u64;
+
+        for i in 0..self.digits.len().max(rhs.digits.len()) {
+            let sum = *self.digits.get(i).unwrap_or(&0) as u64 + *rhs.digits.get(i).unwrap_or(&0) as u64 + carry;
+            digits.push(sum as u32);
+            carry = sum >> 32;
+        }
+
+        if carry > 0 {
+            digits.push(carry as u32);
+        }
+
+        return Natural { digits };
+    }
+
+    /// Subtraction that fails if `rhs` is greater than `self`.
+    pub fn checked_sub(&self, rhs: &Natural) -> Option<Natural> {
+        if rhs > self {
+            return None;
+        }
+
+        let mut digits = Vec::with_capacity(self.digits.len());
+        let mut borrow = 0u64;
+
+        for i in 0..self.digits.len() {
+            let lhs = self.digits[i] as u64;
+            let subtrahend = *rhs.digits.get(i).unwrap_or(&0) as u64 + borrow;
+
+            if lhs >= subtrahend {
+                digits.push((lhs - subtrahend) as u32);
+                borrow = 0;
+            } else {
+                digits.push((lhs + BASE - subtrahend) as u32);
+                borrow = 1;
+            }
+        }
+
+        let mut natural = Natural { digits };
+        natural.trim();
+
+        return Some(natural);
+    }
+
+    /// Natural number subtraction, `max(0, self - rhs)`.
+    pub fn monus(&self, rhs: &Natural) -> Natural {
+        return self.checked_sub(rhs).unwrap_or_default();
+    }
+
+    pub fn mul(&self, rhs: &Natural) -> Natural {
+        let mut digits = vec![0u32; self.digits.len() + rhs.digits.len()];
+
+        for (i, lhs_digit) in self.digits.iter().enumerate() {
+            let mut carry = 0u64;
+
+            for (j, rhs_digit) in rhs.digits.iter().enumerate() {
+                let product = *lhs_digit as u64 * *rhs_digit as u64 + digits[i + j] as u64 + carry;
+                digits[i + j] = product as u32;
+                carry = product >> 32;
+            }
+
+            digits[i + rhs.digits.len()] = carry as u32;
+        }
+
+        let mut natural = Natural { digits };
+        natural.trim();
+
+        return natural;
+    }
+
+    pub fn pow(&self, exponent: &Natural) -> Natural {
+        let mut result = Natural::from(1u32);
+        let mut base = self.clone();
+
+        // square and multiply, from the lowest bit of the exponent
+        for (i, digit) in exponent.digits.iter().enumerate() {
+            for bit in 0..32 {
+                if digit >> bit & 1 == 1 {
+                    result = result.mul(&base);
+                }
+
+                if i == exponent.digits.len() - 1 && digit >> bit <= 1 {
+                    break;
+                }
+
+                base = base.mul(&base);
+            }
+        }
+
+        return result;
+    }
+
+    /// Floor division and remainder, `None` if `rhs` is zero.
+    pub fn div_rem(&self, rhs: &Natural) -> Option<(Natural, Natural)> {
+        if rhs.is_zero() {
+            return None;
+        }
+
+        if let [divisor] = rhs.digits[..] {
+            let (quotient, remainder) = self.div_small(divisor);
+            return Some((quotient, Natural::from(remainder)));
+        }
+
+        // binary long division, one bit of the quotient at a time
+        let mut quotient = Natural { digits: vec![0; self.digits.len()] };
+        let mut remainder = Natural::zero();
+
+        for i in (0..self.digits.len() * 32).rev() {
+            remainder = remainder.add(&remainder);
+
+            if self.digits[i / 32] >> (i % 32) & 1 == 1 {
+                remainder = remainder.add(&Natural::from(1u32));
+            }
+
+            if remainder >= *rhs {
+                remainder = remainder.monus(rhs);
+                quotient.digits[i / 32] |= 1 << (i % 32);
+            }
+        }
+
+        quotient.trim();
+
+        return Some((quotient, remainder));
+    }
+
+    fn mul_add_small(&mut self, factor: u32, summand: u32) {
+        let mut carry = summand as u64;
+
+        for digit in self.digits.iter_mut() {
+            let product = *digit as u64 * factor as u64 + carry;
+            *digit = product as u32;
+            carry = product >> 32;
+        }
+
+        if carry > 0 {
+            self.digits.push(carry as u32);
+        }
+    }
+
+    fn div_small(&self, divisor: u32) -> (Natural, u32) {
+        let mut digits = vec![0; self.digits.len()];
+        let mut remainder = 0u64;
+
+        for i in (0..self.digits.len()).rev() {
+            let current = remainder << 32 | self.digits[i] as u64;
+            digits[i] = (current / divisor as u64) as u32;
+            remainder = current % divisor as u64;
+        }
+
+        let mut quotient = Natural { digits };
+        quotient.trim();
+
+        return (quotient, remainder as u32);
+    }
+
+    fn trim(&mut self) {
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+    }
+}
@@ -0,0 +1,15 @@
+/// The position of a token in the source file, kept in the tree for errors found after parsing.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SourceLocation {
+    pub line: u32,
+    pub column: u32
+}
+
+impl SourceLocation {
+    pub fn new(line: u32, column: u32) -> Self {
+        SourceLocation {
+            line,
+            column
+        }
+    }
+}
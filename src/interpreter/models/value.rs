@@ -0,0 +1,87 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use crate::interpreter::models::Natural;
+
+/// A runtime value. By default the While language only knows natural numbers, which are unbounded.
+/// Values can only become negative if the program runs with a signed numeric mode.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Value {
+    magnitude: Natural,
+    negative: bool
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+
+        write!(f, "{}", self.magnitude)
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (negative, _) => match negative {
+                true => Ordering::Less,
+                false => Ordering::Greater
+            }
+        };
+    }
+}
+
+impl From<u32> for Value {
+    fn from(value: u32) -> Self {
+        return Value::from(Natural::from(value));
+    }
+}
+
+impl From<Natural> for Value {
+    fn from(magnitude: Natural) -> Self {
+        Value {
+            magnitude,
+            negative: false
+        }
+    }
+}
+
+impl Value {
+    pub fn integer(value: i128) -> Self {
+        Value {
+            magnitude: Natural::from(value.unsigned_abs()),
+            negative: value < 0
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        return self.magnitude.is_zero();
+    }
+
+    pub fn is_negative(&self) -> bool {
+        return self.negative;
+    }
+
+    /// The absolute value, which is the value itself for natural numbers.
+    pub fn magnitude(&self) -> &Natural {
+        return &self.magnitude;
+    }
+
+    /// Returns the value if it fits into an `i128`, which every value of a fixed width mode does.
+    pub fn to_i128(&self) -> Option<i128> {
+        let magnitude = i128::try_from(self.magnitude.to_u128()?).ok()?;
+
+        return match self.negative {
+            true => Some(-magnitude),
+            false => Some(magnitude)
+        };
+    }
+}
@@ -1,12 +1,13 @@
 use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
+use std::io::{self, BufRead, BufReader};
 
+use super::errors::{ErrorKind, WhileError};
 use super::models::CodeLine;
 
+/// Removes comments and blank lines. The remaining lines keep their original line number and
+/// column, so diagnostics point at the real source location.
 pub fn normalize(source_code: &Vec<CodeLine>) -> Vec<CodeLine> {
     let mut source = Vec::new();
-    let mut clc: u32 = 1;
 
     for line in source_code {
         if line.line.trim().starts_with("//") {
@@ -14,35 +15,41 @@ pub fn normalize(source_code: &Vec<CodeLine>) -> Vec<CodeLine> {
         }
 
         if !line.line.is_empty() && !line.line.trim().is_empty() {
-            source.push(CodeLine::new(&line.line, clc));
-            clc += 1;
+            source.push(line.clone());
         }
     }
 
     return source;
 }
 
-pub fn read(input: &str) -> Result<Vec<CodeLine>, String> {
-    match read_lines(input) {
-        Ok(lines) => {
-            let mut source_code: Vec<CodeLine> = Vec::new();
-            let mut i: u32 = 1;
-
-            for l in lines {
-                if let Ok(line) = l {
-                    source_code.push(CodeLine::new(&line, i));
-                }
-                i += 1;
-            }
-
-            Ok(source_code)
-        },
-        Err(err) => panic!("Error reading input source code. {}", err)
+/// Reads the source code of a file, or of stdin if the path is `-`.
+pub fn read(input: &str) -> Result<Vec<CodeLine>, WhileError> {
+    if input == "-" {
+        return read_lines(io::stdin().lock(), "stdin");
     }
+
+    return match File::open(input) {
+        Ok(file) => read_lines(BufReader::new(file), input),
+        Err(error) => Err(WhileError::new(ErrorKind::Io, format!("Can't read {}: {}", input, error), 0, 0))
+    };
+}
+
+/// Splits source code that is given as a string, e.g. on the command line, into code lines.
+pub fn split(source: &str) -> Vec<CodeLine> {
+    return source.lines().enumerate().map(|(i, line)| CodeLine::new(line, (i + 1) as u32)).collect();
 }
 
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where P: AsRef<Path>, {
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
-}
\ No newline at end of file
+fn read_lines(reader: impl BufRead, name: &str) -> Result<Vec<CodeLine>, WhileError> {
+    let mut source_code = Vec::new();
+
+    for (i, line) in reader.lines().enumerate() {
+        let line_number = (i + 1) as u32;
+
+        match line {
+            Ok(line) => source_code.push(CodeLine::new(&line, line_number)),
+            Err(error) => return Err(WhileError::new(ErrorKind::Io, format!("Can't read line {} of {}: {}", line_number, name, error), line_number, 1))
+        }
+    }
+
+    return Ok(source_code);
+}
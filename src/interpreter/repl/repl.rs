@@ -0,0 +1,255 @@
+use std::collections::HashSet;
+use crate::interpreter::analysis::Analyzer;
+use crate::interpreter::errors::WhileError;
+use crate::interpreter::executor_states::{ExecutionContext, RunTimeOptions};
+use crate::interpreter::lexer::Lexer;
+use crate::interpreter::lexer::parser::Dialect;
+use crate::interpreter::lexer::scanner::{Keyword, Scanner, SourceTokenKind};
+use crate::interpreter::lexer::scopes::TopLevelScope;
+use crate::interpreter::macros::MacroDefinition;
+use crate::interpreter::models::{BodyExecutor, CodeLine, Environment, MethodsList};
+use crate::interpreter::utils::logging::{Logger, SourceViewElement, TreeViewElement};
+use crate::interpreter::utils::output::OutputWriter;
+use crate::interpreter::{normalize, read};
+
+const HELP: [&str; 7] = [
+    ":vars          lists the global variables",
+    ":methods       lists the defined methods",
+    ":ast           prints the tree of everything that was entered",
+    ":reset         forgets all variables, methods and macros",
+    ":load <file>   runs a file in this session",
+    ":help          prints this help",
+    ":quit          ends the session",
+];
+
+/// An interactive session. Every complete input is parsed, checked and run in the same execution
+/// context, so variables, methods and macros stay defined for the following inputs.
+///
+/// An input is complete at the end of a statement. A block is complete at its closing `#`, a method
+/// at the return of its body and a macro at its closing `}`, until then the lines are collected.
+/// Lines starting with `:` are commands. Errors are returned as messages and leave the session
+/// usable, the effects of the statements before a runtime error are kept.
+pub struct Repl {
+    logger: Logger,
+    dialect: Dialect,
+    context: ExecutionContext,
+    program: TopLevelScope,
+    macros: Vec<CodeLine>,
+    pending: Vec<CodeLine>,
+    line_number: u32,
+    closed: bool
+}
+
+impl Repl {
+    pub fn new(logger: Logger, dialect: Dialect, options: RunTimeOptions, output: Box<dyn OutputWriter>) -> Self {
+        let mut context = ExecutionContext::with_output(&[], output);
+        context.options = options;
+
+        Repl {
+            logger,
+            dialect,
+            context,
+            program: TopLevelScope::new(Logger::NoLogger),
+            macros: Vec::new(),
+            pending: Vec::new(),
+            line_number: 0,
+            closed: false
+        }
+    }
+
+    /// Whether the lines entered so far are waiting for the end of a block.
+    pub fn is_pending(&self) -> bool {
+        return !self.pending.is_empty();
+    }
+
+    /// Whether `:quit` was entered.
+    pub fn is_closed(&self) -> bool {
+        return self.closed;
+    }
+
+    pub fn context(&self) -> &ExecutionContext {
+        return &self.context;
+    }
+
+    /// Handles one line of input. Returns the messages for the user, the output of the program
+    /// goes to the output writer of the session.
+    pub fn input(&mut self, line: &str) -> Vec<String> {
+        if line.trim_start().starts_with(':') {
+            return self.command(line.trim());
+        }
+
+        self.line_number += 1;
+        let code_lines = normalize(&vec![CodeLine::new(line, self.line_number)]);
+
+        if code_lines.is_empty() {
+            return Vec::new();
+        }
+
+        self.pending.extend(code_lines);
+
+        if !Repl::is_complete(&self.pending) {
+            return Vec::new();
+        }
+
+        let code_lines = std::mem::take(&mut self.pending);
+        return self.execute(code_lines);
+    }
+
+    fn command(&mut self, command: &str) -> Vec<String> {
+        let (name, argument) = match command.split_once(char::is_whitespace) {
+            Some((name, argument)) => (name, argument.trim()),
+            None => (command, "")
+        };
+
+        return match name {
+            ":vars" => {
+                let globals = self.context.environment.globals();
+                let mut names = globals.keys().collect::<Vec<&String>>();
+                names.sort();
+
+                match names.is_empty() {
+                    true => vec!["No variables".to_string()],
+                    false => names.iter().map(|name| format!("{} = {}", name, globals[*name])).collect()
+                }
+            }
+            ":methods" => match self.program.methods.is_empty() {
+                true => vec!["No methods".to_string()],
+                false => self.program.methods.iter()
+                    .map(|method| method.header_token.to_source()[0].trim_end_matches(':').to_string())
+                    .collect()
+            },
+            ":ast" => self.program.to_tree_view(),
+            ":reset" => {
+                self.context.environment = Environment::new();
+                self.context.methods = MethodsList::new();
+                self.program = TopLevelScope::new(Logger::NoLogger);
+                self.macros.clear();
+                self.pending.clear();
+
+                vec!["The session was reset".to_string()]
+            }
+            ":load" if argument.is_empty() => vec!["Usage: :load <file>".to_string()],
+            ":load" => match read(argument) {
+                Ok(code_lines) => self.execute(normalize(&code_lines)),
+                Err(error) => vec![error.to_string()]
+            },
+            ":help" => HELP.iter().map(|line| line.to_string()).collect(),
+            ":quit" | ":exit" => {
+                self.closed = true;
+                Vec::new()
+            }
+            _ => vec![format!("Unknown command {}, :help lists the commands", name)]
+        };
+    }
+
+    /// Parses, checks and runs a complete input. The macros of earlier inputs are put in front of it,
+    /// the methods of earlier inputs are known to the check.
+    fn execute(&mut self, code_lines: Vec<CodeLine>) -> Vec<String> {
+        let mut source = self.macros.clone();
+        source.extend(code_lines.iter().cloned());
+
+        let scope = match Lexer::with_dialect(self.logger.clone(), self.dialect).tokenize_avoiding(source, &self.names()) {
+            Ok(scope) => scope,
+            Err(errors) => return Repl::messages(&errors)
+        };
+
+        let mut program = TopLevelScope::new(Logger::NoLogger);
+        program.methods = self.program.methods.iter().chain(scope.methods.iter()).cloned().collect();
+        program.stack = scope.stack.clone();
+
+        if let Err(errors) = Analyzer::analyse_with_dialect(&program, &code_lines, self.dialect) {
+            return Repl::messages(&errors);
+        }
+
+        self.macros.extend(Repl::macro_definitions(&code_lines));
+
+        for method in &scope.methods {
+            self.context.methods.insert(method.header_token.name.value.clone(), method.clone());
+        }
+
+        self.program.methods = program.methods;
+        self.program.stack.extend(scope.stack.iter().cloned());
+
+        let body_executor = BodyExecutor {
+            scope: scope.stack
+        };
+
+        if let Err(error) = body_executor.execute(&mut self.context) {
+            return Repl::messages(&[error.with_source(&code_lines)]);
+        }
+
+        return Vec::new();
+    }
+
+    /// The lines are complete if every block is closed, a method has reached the return of its
+    /// body and a macro its closing `}`.
+    fn is_complete(code_lines: &[CodeLine]) -> bool {
+        let tokens = Scanner::scan(code_lines);
+
+        match tokens.first().map(|token| &token.kind) {
+            Some(SourceTokenKind::Keyword(Keyword::Macro)) => {
+                return code_lines.last().is_some_and(|code_line| code_line.line.trim() == "}");
+            }
+            Some(SourceTokenKind::Keyword(Keyword::Num)) | Some(SourceTokenKind::Keyword(Keyword::Void)) => {
+                let mut depth = 0;
+
+                for token in &tokens {
+                    match token.kind {
+                        SourceTokenKind::Keyword(Keyword::Return) if depth == 0 => return true,
+                        SourceTokenKind::Keyword(Keyword::While | Keyword::Loop | Keyword::If | Keyword::Else) => depth += 1,
+                        SourceTokenKind::Hash => depth -= 1,
+                        _ => {}
+                    }
+                }
+
+                return false;
+            }
+            _ => {}
+        }
+
+        let depth = tokens.iter().fold(0, |depth, token| match token.kind {
+            SourceTokenKind::Keyword(Keyword::While | Keyword::Loop | Keyword::If | Keyword::Else) => depth + 1,
+            SourceTokenKind::Hash => depth - 1,
+            _ => depth
+        });
+
+        return depth <= 0;
+    }
+
+    /// The names used by earlier inputs, which the temporaries of macros must not take.
+    fn names(&self) -> HashSet<String> {
+        let code_lines = self.program.to_source().iter().map(|line| CodeLine::new_from_line(line)).collect::<Vec<CodeLine>>();
+
+        return Scanner::scan(&code_lines).into_iter()
+            .filter_map(|token| match token.kind {
+                SourceTokenKind::Name(name) => Some(name),
+                _ => None
+            })
+            .chain(self.context.environment.globals().keys().cloned())
+            .collect();
+    }
+
+    /// The lines of the macro definitions among the code lines.
+    fn macro_definitions(code_lines: &[CodeLine]) -> Vec<CodeLine> {
+        let mut definitions = Vec::new();
+        let mut index = 0;
+
+        while index < code_lines.len() {
+            if MacroDefinition::at_definition(&Scanner::scan_code_line(&code_lines[index])) {
+                if let Ok((_, next)) = MacroDefinition::parse(code_lines, index) {
+                    definitions.extend(code_lines[index..next].iter().cloned());
+                    index = next;
+                    continue;
+                }
+            }
+
+            index += 1;
+        }
+
+        return definitions;
+    }
+
+    fn messages(errors: &[WhileError]) -> Vec<String> {
+        return errors.iter().map(|error| error.to_string()).collect();
+    }
+}
@@ -16,21 +16,25 @@ impl<T> VecNameTokenExtension for Vec<T> where T : TreeViewElement + PartialEq {
         let last = self.last().unwrap();
 
         for parameter in self {
-            string.push_str(&format!("{}", parameter.to_tree_view()[0]));
+            string.push_str(&parameter.to_tree_view()[0]);
 
             if last != parameter {
                 string.push_str(", ");
             }
         }
 
-        string.push_str("]");
+        string.push(']');
         return string;
     }
 
     fn to_multi_line_string(&self) -> String {
-        let last_element = self.last().unwrap();
+        let last_element = match self.last() {
+            Some(last_element) => last_element,
+            None => return String::from("[]")
+        };
+
         let mut string = String::from("[");
-        string.push_str("\n");
+        string.push('\n');
 
         for value in self.iter() {
             string.push_str(&format!("\t{}", value.to_tree_view()[0]));
@@ -1,5 +1,7 @@
 mod logger;
+mod source_view;
 mod tree_view;
 
+pub use source_view::SourceViewElement;
 pub use tree_view::TreeViewElement;
-pub use logger::Logger;
\ No newline at end of file
+pub use logger::Logger;
@@ -0,0 +1,23 @@
+use crate::interpreter::lexer::models::Stackable;
+
+/// Prints an element as `.while` source code, which parses to the same element again.
+/// Bodies of blocks are indented by four spaces.
+pub trait SourceViewElement {
+    fn to_source(&self) -> Vec<String>;
+}
+
+impl SourceViewElement for Stackable {
+    fn to_source(&self) -> Vec<String> {
+        match self {
+            Stackable::MethodCallToken { value } => vec![format!("{};", value.to_source()[0])],
+            Stackable::VariableToken { value } => value.to_source(),
+            Stackable::WhileToken { value } => value.to_source(),
+            Stackable::LoopToken { value } => value.to_source(),
+            Stackable::IfToken { value } => value.to_source(),
+            Stackable::ReturnToken { value } => value.to_source(),
+            Stackable::LoopControlToken { value } => value.to_source(),
+            Stackable::OperatorToken { value } => value.to_source(),
+            Stackable::WritelnToken { value } => value.to_source(),
+        }
+    }
+}
@@ -10,8 +10,12 @@ impl TreeViewElement for Stackable {
             Stackable::MethodCallToken { value } => value.to_tree_view(),
             Stackable::VariableToken { value } => value.to_tree_view(),
             Stackable::WhileToken { value } => value.to_tree_view(),
+            Stackable::LoopToken { value } => value.to_tree_view(),
+            Stackable::IfToken { value } => value.to_tree_view(),
             Stackable::ReturnToken { value } => { value.to_tree_view() }
-            Stackable::AdditiveOperatorToken { value } => { value.to_tree_view() }
+            Stackable::LoopControlToken { value } => value.to_tree_view(),
+            Stackable::OperatorToken { value } => { value.to_tree_view() }
+            Stackable::WritelnToken { value } => value.to_tree_view(),
         }
     }
 }
\ No newline at end of file
@@ -1,4 +1,3 @@
 pub mod logging;
-pub mod interpreter_watcher;
-pub mod extension_methods;
-pub mod env_args_parser;
\ No newline at end of file
+pub mod output;
+pub mod extension_methods;
\ No newline at end of file
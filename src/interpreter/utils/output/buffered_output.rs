@@ -0,0 +1,25 @@
+use std::sync::{Arc, Mutex};
+use crate::interpreter::utils::output::OutputWriter;
+
+/// Collects the output of the program. Clones share the same buffer, so a clone can be handed
+/// to the runtime while the original is used to read the output afterwards.
+#[derive(Clone, Default)]
+pub struct BufferedOutput {
+    lines: Arc<Mutex<Vec<String>>>
+}
+
+impl BufferedOutput {
+    pub fn new() -> Self {
+        return BufferedOutput::default();
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        return self.lines.lock().unwrap().clone();
+    }
+}
+
+impl OutputWriter for BufferedOutput {
+    fn write_line(&mut self, line: &str) {
+        self.lines.lock().unwrap().push(line.to_string());
+    }
+}
@@ -0,0 +1,7 @@
+mod output_writer;
+mod std_output;
+mod buffered_output;
+
+pub use output_writer::OutputWriter;
+pub use std_output::StdOutput;
+pub use buffered_output::BufferedOutput;
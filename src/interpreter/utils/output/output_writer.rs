@@ -0,0 +1,5 @@
+/// Receives the output of a running program, e.g. of `writeln`. Writers are `Send`, so a runtime
+/// can be moved to another thread together with its output.
+pub trait OutputWriter: Send {
+    fn write_line(&mut self, line: &str);
+}
@@ -0,0 +1,11 @@
+use crate::interpreter::utils::output::OutputWriter;
+
+/// Prints the output of the program to stdout.
+#[derive(Clone, Default)]
+pub struct StdOutput;
+
+impl OutputWriter for StdOutput {
+    fn write_line(&mut self, line: &str) {
+        println!("{}", line);
+    }
+}
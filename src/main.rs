@@ -1,47 +1,188 @@
-use interpreter::read;
-use crate::interpreter::executor_states::RunTime;
-use crate::interpreter::normalize;
-use crate::interpreter::lexer::scopes::TopLevelScope;
-use crate::interpreter::lexer::Lexer;
-use crate::interpreter::utils::env_args_parser;
-use crate::interpreter::utils::interpreter_watcher::{pseudo_status, pseudo_throw};
-use crate::interpreter::utils::logging::Logger;
+use std::env;
+use std::io::{self, Write};
+use std::process::ExitCode;
+use while_interpreter::interpreter::analysis::Analyzer;
+use while_interpreter::interpreter::cli::{check_formattable, CliArguments, Command, ExitStatus, Source, USAGE};
+use while_interpreter::interpreter::desugar::{check_equivalence, Desugarer};
+use while_interpreter::interpreter::errors::{ErrorKind, WhileError};
+use while_interpreter::interpreter::executor_states::RunTime;
+use while_interpreter::interpreter::normalize;
+use while_interpreter::interpreter::lexer::scopes::TopLevelScope;
+use while_interpreter::interpreter::lexer::Lexer;
+use while_interpreter::interpreter::macros::MacroExpander;
+use while_interpreter::interpreter::repl::Repl;
+use while_interpreter::interpreter::utils::logging::{SourceViewElement, TreeViewElement};
+use while_interpreter::interpreter::utils::output::StdOutput;
 
-mod interpreter;
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<String>>();
 
-fn main() {
-    let path = env_args_parser::get_suffix_from_prefix(&["-i", "i"][..]).unwrap_or_else(||{
-        pseudo_throw("No source file provided. Consider using --i example.while".to_string());
-        return String::from("");
-    });
+    let arguments = match CliArguments::parse(&args) {
+        Ok(arguments) => arguments,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            return ExitStatus::Usage.into();
+        }
+    };
+
+    let status = match arguments.command {
+        Command::Help => {
+            println!("{}", USAGE);
+            ExitStatus::Success
+        }
+        Command::Repl => repl(&arguments),
+        _ => execute(&arguments)
+    };
+
+    return match arguments.result {
+        Some(_) => ExitCode::from(status.code_with_result()),
+        None => status.into()
+    };
+}
+
+/// Runs every command but the REPL on the program. Diagnostics are written to stderr, the exit
+/// status tells which phase failed.
+fn execute(arguments: &CliArguments) -> ExitStatus {
+    let Some(source) = &arguments.source else {
+        return ExitStatus::Usage;
+    };
+
+    let source_code = match source.read() {
+        Ok(source_code) => source_code,
+        Err(error) => return report(&[error], ExitStatus::Io)
+    };
+
+    if arguments.command == Command::Fmt {
+        if let Err(errors) = check_formattable(&source_code) {
+            return report(&errors, ExitStatus::Unformattable);
+        }
+    }
+
+    let source_code = normalize(&source_code);
+
+    if arguments.command == Command::Expand {
+        return match MacroExpander::expand(&source_code) {
+            Ok(expanded) => {
+                for code_line in expanded {
+                    println!("{}", code_line.line);
+                }
+                ExitStatus::Success
+            },
+            Err(errors) => report(&errors, ExitStatus::Syntax)
+        };
+    }
+
+    let tokenizer = Lexer::with_dialect(arguments.logger.clone(), arguments.dialect);
+    let scope: TopLevelScope = match tokenizer.tokenize(source_code.clone()) {
+        Ok(scope) => scope,
+        Err(errors) => return report(&errors, ExitStatus::Syntax)
+    };
+
+    match arguments.command {
+        Command::Ast => return print(scope.to_tree_view()),
+        Command::Fmt => return print(scope.to_source()),
+        _ => {}
+    }
 
-    let logger_statement = env_args_parser::get_suffix_from_prefix(&["-log", "log"][..]).unwrap_or("np".to_string());
+    if let Err(errors) = Analyzer::analyse_with_dialect(&scope, &source_code, arguments.dialect) {
+        return report(&errors, ExitStatus::Semantic);
+    }
+
+    match arguments.command {
+        Command::Check => {
+            println!("{}: no errors", source);
+            return ExitStatus::Success;
+        }
+        Command::Desugar if arguments.check => {
+            return match check_equivalence(&scope) {
+                Ok(_) => {
+                    println!("The desugared program is equivalent");
+                    ExitStatus::Success
+                },
+                Err(error) if error.kind == ErrorKind::NotEquivalent => report(&[error], ExitStatus::NotEquivalent),
+                Err(error) => report(&[error.with_source(&source_code)], ExitStatus::Runtime)
+            };
+        }
+        Command::Desugar => {
+            let mut desugarer = Desugarer::new(&scope);
+            return print(desugarer.desugar(&scope).to_source());
+        }
+        _ => {}
+    }
 
+    let mut run_time = RunTime::with_options(scope, arguments.logger.clone(), Box::new(StdOutput), arguments.options);
+
+    if arguments.command == Command::Trace {
+        run_time.context_mut().trace_to(Box::new(StdOutput));
+    }
 
+    if let Err(error) = run_time.run() {
+        return report(&[error.with_source(&source_code)], ExitStatus::Runtime);
+    }
 
-    let logger: Logger = match logger_statement.to_lowercase().as_ref() {
-        "nolog" => Logger::NoLogger,
-        "log" => Logger::StdLogger,
-        "np" => {
-            println!("Logging argument not provided. Using default: nolog");
-            Logger::NoLogger
+    return match &arguments.result {
+        Some(name) => match run_time.context().environment.globals().get(name) {
+            Some(value) => ExitStatus::result(value),
+            None => {
+                eprintln!("The result variable {} isn't defined after the program", name);
+                ExitStatus::Runtime
+            }
         },
-        _ => { Logger::NoLogger }
+        None => ExitStatus::Success
     };
+}
 
+fn print(lines: Vec<String>) -> ExitStatus {
+    for line in lines {
+        println!("{}", line);
+    }
+
+    return ExitStatus::Success;
+}
+
+fn report(errors: &[WhileError], status: ExitStatus) -> ExitStatus {
+    for error in errors {
+        eprintln!("{}", error);
+    }
+
+    return status;
+}
+
+/// Reads inputs from stdin until `:quit` or the end of the input. A program given on the command
+/// line runs first.
+fn repl(arguments: &CliArguments) -> ExitStatus {
+    let mut repl = Repl::new(arguments.logger.clone(), arguments.dialect, arguments.options, Box::new(StdOutput));
+    println!("while-interpreter REPL, :help lists the commands");
+
+    let messages = match &arguments.source {
+        Some(Source::File(path)) => repl.input(&format!(":load {}", path)),
+        Some(Source::Inline(code)) => code.lines().flat_map(|line| repl.input(line)).collect(),
+        None => Vec::new()
+    };
+
+    for message in messages {
+        println!("{}", message);
+    }
 
-    let mut source_code = read(&(path)).unwrap();
-    source_code = normalize(&source_code);
+    while !repl.is_closed() {
+        print!("{}", if repl.is_pending() { "... " } else { ">>> " });
+        let _ = io::stdout().flush();
 
-    let tokenizer = Lexer::new(logger.clone());
-    let scope: TopLevelScope = tokenizer.tokenize(source_code);
+        let mut line = String::new();
 
-    if pseudo_status::get_status() {
-        println!("{}", pseudo_status::get_message());
-        return;
+        match io::stdin().read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {
+                for message in repl.input(line.trim_end_matches(['\n', '\r'])) {
+                    println!("{}", message);
+                }
+            }
+            Err(error) => {
+                eprintln!("Error reading the input. {}", error);
+                return ExitStatus::Io;
+            }
+        }
     }
 
-    let mut run_time = RunTime::new(scope, logger.clone());
-    // todo: make an iterator out of this, so you can terminate the program if a runtime error occurs.
-    run_time.run();
+    return ExitStatus::Success;
 }
@@ -0,0 +1,168 @@
+use while_interpreter::interpreter::cli::{check_formattable, CliArguments, Command, ExitStatus, Source};
+use while_interpreter::interpreter::errors::ErrorKind;
+use while_interpreter::interpreter::executor_states::{NumericMode, UnderflowMode};
+use while_interpreter::interpreter::lexer::parser::Dialect;
+use while_interpreter::interpreter::models::Value;
+use while_interpreter::interpreter::{read, split};
+
+fn parse(args: &[&str]) -> Result<CliArguments, String> {
+    return CliArguments::parse(&args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>());
+}
+
+#[test]
+fn commands_and_files() {
+    let tests = [
+        (vec!["run", "example.while"], Command::Run, Some("example.while")),
+        (vec!["my-example.while"], Command::Run, Some("my-example.while")),
+        (vec!["check", "dir-1/a=b.while"], Command::Check, Some("dir-1/a=b.while")),
+        (vec!["ast", "x.while"], Command::Ast, Some("x.while")),
+        (vec!["fmt", "x.while"], Command::Fmt, Some("x.while")),
+        (vec!["trace", "x.while"], Command::Trace, Some("x.while")),
+        (vec!["expand", "x.while"], Command::Expand, Some("x.while")),
+        (vec!["desugar", "--check", "x.while"], Command::Desugar, Some("x.while")),
+        (vec!["run", "--", "-x.while"], Command::Run, Some("-x.while")),
+        (vec!["run", "--", "check"], Command::Run, Some("check")),
+        (vec!["repl"], Command::Repl, None),
+        (vec!["repl", "x.while"], Command::Repl, Some("x.while")),
+        (vec!["help"], Command::Help, None),
+        (vec!["run", "--help"], Command::Help, None),
+        (vec!["-h"], Command::Help, None),
+    ];
+
+    for (args, command, path) in tests {
+        let arguments = parse(&args).unwrap();
+
+        assert_eq!(arguments.command, command, "{:?}", args);
+        assert_eq!(arguments.source, path.map(|path| Source::File(path.to_string())), "{:?}", args);
+    }
+}
+
+#[test]
+fn stdin_and_inline_sources() {
+    let tests = [
+        (vec!["-"], Command::Run, Source::File("-".to_string())),
+        (vec!["check", "-", "--dialect=extended"], Command::Check, Source::File("-".to_string())),
+        (vec!["-e", "x = 1;"], Command::Run, Source::Inline("x = 1;".to_string())),
+        (vec!["ast", "--eval", "writeln(2);"], Command::Ast, Source::Inline("writeln(2);".to_string())),
+        (vec!["trace", "--eval=x = 1; y = x;"], Command::Trace, Source::Inline("x = 1; y = x;".to_string())),
+        (vec!["repl", "-e", "x = 1;"], Command::Repl, Source::Inline("x = 1;".to_string())),
+    ];
+
+    for (args, command, source) in tests {
+        let arguments = parse(&args).unwrap();
+
+        assert_eq!(arguments.command, command, "{:?}", args);
+        assert_eq!(arguments.source, Some(source), "{:?}", args);
+    }
+
+    assert!(Source::File("-".to_string()).is_stdin());
+    assert_eq!(Source::File("-".to_string()).to_string(), "stdin");
+    assert_eq!(Source::Inline("x = 1;".to_string()).to_string(), "-e");
+
+    let code_lines = Source::Inline("x = 1;\n\nwriteln(x);\r\n".to_string()).read().unwrap();
+    assert_eq!(code_lines.iter().map(|code_line| (code_line.line.as_str(), code_line.line_number)).collect::<Vec<(&str, u32)>>(),
+               vec![("x = 1;", 1), ("", 2), ("writeln(x);", 3)]);
+    assert_eq!(split("").len(), 0);
+
+    let error = read("does/not/exist.while").err().unwrap();
+    assert_eq!(error.kind, ErrorKind::Io);
+    assert!(error.message.contains("does/not/exist.while"), "{}", error.message);
+}
+
+#[test]
+fn options() {
+    let arguments = parse(&["run", "--dialect", "extended", "x.while", "--numeric=u8-wrap", "--underflow=ERROR"]).unwrap();
+
+    assert_eq!(arguments.dialect, Dialect::Extended);
+    assert_eq!(arguments.options.numeric, NumericMode::analyse("u8-wrap").unwrap());
+    assert_eq!(arguments.options.underflow, UnderflowMode::Error);
+    assert!(!arguments.check);
+    assert_eq!(arguments.result, None);
+
+    let arguments = parse(&["trace", "x.while", "--result", "Total"]).unwrap();
+    assert_eq!(arguments.result.as_deref(), Some("Total"));
+
+    let arguments = parse(&["x.while"]).unwrap();
+
+    assert_eq!(arguments.dialect, Dialect::Classic);
+    assert_eq!(arguments.options.numeric, NumericMode::Natural);
+    assert_eq!(arguments.options.underflow, UnderflowMode::Saturate);
+}
+
+#[test]
+fn invalid_command_lines() {
+    let tests = [
+        (vec![], "No source file given"),
+        (vec!["run"], "No source file given"),
+        (vec!["run", "a.while", "b.while"], "only one program"),
+        (vec!["run", "a.while", "-e", "x = 1;"], "only one program"),
+        (vec!["-e", "x = 1;", "--eval=y = 2;"], "only one program"),
+        (vec!["run", "-e"], "needs a value"),
+        (vec!["repl", "-"], "can't read a program from there"),
+        (vec!["run", "--bogus", "a.while"], "Unknown option \"--bogus\""),
+        (vec!["run", "a.while", "--dialect"], "needs a value"),
+        (vec!["run", "a.while", "--dialect=pascal"], "Unknown dialect \"pascal\""),
+        (vec!["run", "a.while", "--numeric", "u7-wrap"], "Unknown numeric mode"),
+        (vec!["run", "a.while", "--underflow=wrap"], "Unknown underflow mode"),
+        (vec!["run", "a.while", "--log=yes"], "doesn't take a value"),
+        (vec!["run", "a.while", "--check"], "only applies to desugar"),
+        (vec!["check", "a.while", "--result=x"], "only applies to run and trace"),
+        (vec!["run", "a.while", "--result"], "needs a value"),
+        (vec!["-i=a.while"], "Unknown option"),
+    ];
+
+    for (args, message) in tests {
+        let error = parse(&args).err().unwrap();
+        assert!(error.contains(message), "{:?}: {}", args, error);
+    }
+}
+
+#[test]
+fn exit_codes() {
+    let statuses = [
+        ExitStatus::Success,
+        ExitStatus::Runtime,
+        ExitStatus::Usage,
+        ExitStatus::Syntax,
+        ExitStatus::Semantic,
+        ExitStatus::Io,
+        ExitStatus::NotEquivalent,
+        ExitStatus::Unformattable,
+    ];
+
+    let codes = statuses.iter().map(ExitStatus::code).collect::<Vec<u8>>();
+    assert_eq!(codes, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+    let codes = statuses.iter().map(ExitStatus::code_with_result).collect::<Vec<u8>>();
+    assert_eq!(codes, [0, 129, 130, 131, 132, 133, 134, 135]);
+
+    let tests = [(0, 0), (42, 42), (127, 127), (128, 0), (300, 44), (-1, 127), (-128, 0), (-300, 84)];
+
+    for (value, code) in tests {
+        assert_eq!(ExitStatus::result(&Value::integer(value)), ExitStatus::Result(code), "{}", value);
+        assert_eq!(ExitStatus::Result(code).code_with_result(), code);
+    }
+}
+
+#[test]
+fn fmt_refuses_comments_and_macros() {
+    let tests = [
+        ("a = 1;\nwriteln(a);", vec![]),
+        ("// the answer\na = 42;", vec![(1, 1)]),
+        ("a = 1;   // one\nb = 2;", vec![(1, 10)]),
+        ("macro! double $x; = {\n    $x = $x + $x;\n}\na = 1;\ndouble a;", vec![(1, 1)]),
+        ("macro! inc $x; = { // adds one\n    $x += 1;\n}", vec![(1, 1), (1, 20)]),
+    ];
+
+    for (code, expected) in tests {
+        let locations = match check_formattable(&split(code)) {
+            Ok(_) => vec![],
+            Err(errors) => {
+                assert!(errors.iter().all(|error| error.kind == ErrorKind::Unformattable), "{}", code);
+                errors.iter().map(|error| (error.line, error.column)).collect()
+            }
+        };
+
+        assert_eq!(locations, expected, "{}", code);
+    }
+}
@@ -13,4 +13,9 @@ pub fn gen_code_line(line: &str) -> CodeLine {
 pub fn gen_code_line_and_reset(line: &str) -> CodeLine {
     reset_counter();
     return CodeLine::new(line, unsafe { COUNTER });
-}
\ No newline at end of file
+}
+
+/// Numbers the lines from 1, like they are read from a file.
+pub fn gen_code_lines<S: AsRef<str>>(lines: &[S]) -> Vec<CodeLine> {
+    return lines.iter().enumerate().map(|(i, line)| CodeLine::new(line.as_ref(), (i + 1) as u32)).collect();
+}
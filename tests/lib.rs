@@ -1,4 +1,5 @@
 pub mod tokenizer;
 pub mod runtime;
+pub mod cli;
 
 pub mod code_line_gen;
\ No newline at end of file
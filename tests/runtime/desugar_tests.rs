@@ -0,0 +1,143 @@
+use while_interpreter::interpreter::desugar::check_equivalence;
+use while_interpreter::interpreter::errors::ErrorKind;
+use while_interpreter::interpreter::lexer::Lexer;
+use while_interpreter::interpreter::lexer::parser::Dialect;
+use while_interpreter::interpreter::lexer::scanner::{Keyword, Scanner, SourceTokenKind};
+use while_interpreter::interpreter::lexer::scopes::TopLevelScope;
+use while_interpreter::interpreter::models::CodeLine;
+use while_interpreter::interpreter::utils::logging::Logger::NoLogger;
+use while_interpreter::interpreter::utils::logging::SourceViewElement;
+use crate::code_line_gen::gen_code_lines;
+
+fn tokenize(lines: &[&str]) -> TopLevelScope {
+    return Lexer::with_dialect(NoLogger, Dialect::Extended).tokenize(gen_code_lines(lines)).unwrap();
+}
+
+/// Core While only steps by one and only compares against zero.
+fn is_core(source: &[String]) -> bool {
+    let code_lines = source.iter().map(|line| CodeLine::new_from_line(line)).collect::<Vec<CodeLine>>();
+    let tokens = Scanner::scan(&code_lines);
+
+    return tokens.iter().enumerate().all(|(i, token)| match token.kind {
+        SourceTokenKind::AddAssign | SourceTokenKind::SubAssign => tokens[i + 1].kind == SourceTokenKind::Number("1".to_string()),
+        SourceTokenKind::NotEqual => tokens[i + 1].kind == SourceTokenKind::Number("0".to_string()),
+        SourceTokenKind::Keyword(keyword) => matches!(keyword, Keyword::While | Keyword::Num | Keyword::Void | Keyword::Writeln | Keyword::Return),
+        _ => matches!(token.kind, SourceTokenKind::Name(_) | SourceTokenKind::Number(_) | SourceTokenKind::Assign | SourceTokenKind::LeftParen
+            | SourceTokenKind::RightParen | SourceTokenKind::Comma | SourceTokenKind::Colon | SourceTokenKind::Semicolon
+            | SourceTokenKind::Hash | SourceTokenKind::End)
+    });
+}
+
+#[test]
+fn source_view() {
+    let tests = [
+        ("x = a - (b - c);", "x = a - (b - c);"),
+        ("x = (a - b) - c;", "x = a - b - c;"),
+        ("x = (a ^ b) ^ c;", "x = (a ^ b) ^ c;"),
+        ("x = a ^ (b ^ c);", "x = a ^ b ^ c;"),
+        ("x = (a + b) * Add(c, 2 * d);", "x = (a + b) * Add(c, 2 * d);"),
+        ("x %= 10 / (2 / 1);", "x %= 10 / (2 / 1);"),
+        ("while (a < b or c != 0) and not (d == 0): #", "while (a < b or c != 0) and not d == 0:\n#"),
+    ];
+
+    for (line, expected) in tests {
+        assert_eq!(tokenize(&[line]).to_source().join("\n"), expected);
+    }
+
+    let program = [
+        "num Add(a, b):",
+        "    if a > b:",
+        "        return a;",
+        "    # else:",
+        "        loop b:",
+        "            writeln(b);",
+        "            break;",
+        "        #",
+        "    #",
+        "    return a + b;",
+        "x = Add(1, 2);",
+    ];
+
+    let source = tokenize(&program).to_source();
+    let expected = [
+        "num Add(a, b):",
+        "    if a > b:",
+        "        return a;",
+        "    #",
+        "    else:",
+        "        loop b:",
+        "            writeln(b);",
+        "            break;",
+        "        #",
+        "    #",
+        "    return a + b;",
+        "",
+        "x = Add(1, 2);",
+    ];
+
+    assert_eq!(source, expected);
+    assert_eq!(tokenize(&source.iter().map(String::as_str).collect::<Vec<&str>>()).to_source(), source);
+}
+
+#[test]
+fn equivalent_programs() {
+    let tests = [
+        vec!["x = 5;", "y = x;", "x += 1;", "y -= 1;", "while y != 0:", "    y -= 1;", "    x += 1;", "#"],
+        vec!["x = 7 + 3 * 4 - 20;", "y = 2 ^ 10 / 3 % 7;", "z = 5 - 9 + 1;", "w = 0 ^ 0;"],
+        vec!["x = 6;", "x *= 7;", "y = 100;", "y /= 7;", "z = 100;", "z %= 7;", "w = 3;", "w ^= 4;", "v = 10;", "v += x - 40;", "v -= 3 * 2;"],
+        vec!["a = 0; b = 0;", "loop a:", "    b += 1;", "#", "c = 3;", "loop c:", "    c += 1;", "    b += 2;", "#"],
+        vec!["x = 4; y = 0;", "if x == 4:", "    y = 1;", "# else:", "    y = 2;", "#", "if x < 3:", "    y += 10;", "#"],
+        vec!["i = 0; s = 0;", "while i < 10 and not i == 7:", "    i += 1;", "    s += i;", "#"],
+        vec!["i = 0; j = 0;", "while i <= 3 or j >= 1 and j != 5:", "    i += 1;", "    j += 1;", "#"],
+        vec!["x = 5; y = 6;", "while x > 0 and (y > 3 or x == 1):", "    x -= 1;", "    y -= 1;", "#"],
+        vec![
+            "num Fib(n):",
+            "    if n < 2:",
+            "        return n;",
+            "    #",
+            "    return Fib(n - 1) + Fib(n - 2);",
+            "num Count():",
+            "    calls += 1;",
+            "    return calls;",
+            "void Log(x):",
+            "    writeln(x * 2);",
+            "    return;",
+            "calls = 0;",
+            "f = Fib(10);",
+            "g = calls + Count() + calls;",
+            "Log(f + 1);",
+            "writeln(Count());",
+        ],
+        vec!["num Twice(x):", "    g *= 2;", "    return x;", "g = 3;", "y = Twice(g);", "writeln(g);"],
+        vec!["t_1 = 4;", "t_2 = t_1 * 3 + 1;", "t_3 = t_2 - t_1;"],
+        vec!["i = 0; s = 0;", "while i != 20:", "    i += 1;", "    if i % 3 == 0:", "        continue;", "    #", "    if s > 30:", "        break;", "    #", "    s += i;", "#"],
+        vec!["i = 5; s = 0;", "while i != 0:", "    i -= 1;", "    continue;", "    s += 1;", "#"],
+        vec!["n = 4; s = 0;", "loop n:", "    m = 3;", "    loop m:", "        if m == s:", "            break;", "        #", "        s += 1;", "    #", "    writeln(s);", "#"],
+        vec![
+            "num Find(n):",
+            "    i = 0;",
+            "    while i < n:",
+            "        i += 1;",
+            "        if i * i > n:",
+            "            return i;",
+            "        #",
+            "    #",
+            "    return 0;",
+            "x = Find(30);",
+            "while x != 0:",
+            "    if x == 4:",
+            "        break;",
+            "    #",
+            "    x -= 1;",
+            "#",
+        ],
+    ];
+
+    for lines in tests {
+        let source = check_equivalence(&tokenize(&lines)).unwrap();
+        assert!(is_core(&source), "{:?}\n{}", lines, source.join("\n"));
+    }
+
+    let error = check_equivalence(&tokenize(&["x = 1 / 0;"])).err().unwrap();
+    assert_eq!(error.kind, ErrorKind::DivisionByZero);
+}
@@ -1 +1,4 @@
-pub mod program_tests;
\ No newline at end of file
+pub mod desugar_tests;
+pub mod natural_tests;
+pub mod program_tests;
+pub mod repl_tests;
\ No newline at end of file
@@ -0,0 +1,113 @@
+use std::cmp::Ordering;
+use while_interpreter::interpreter::models::Natural;
+
+fn natural(decimal: &str) -> Natural {
+    return Natural::parse(decimal).unwrap();
+}
+
+#[test]
+fn parse_and_display() {
+    let tests = [
+        ("0", "0"),
+        ("000", "0"),
+        ("42", "42"),
+        ("4294967295", "4294967295"),
+        ("4294967296", "4294967296"),
+        ("1000000000", "1000000000"),
+        ("00012345678901234567890", "12345678901234567890"),
+        ("340282366920938463463374607431768211456", "340282366920938463463374607431768211456"),
+    ];
+
+    for (decimal, expected) in tests {
+        assert_eq!(natural(decimal).to_string(), expected);
+    }
+
+    for invalid in ["", "-1", "1a", " 1", "1.5"] {
+        assert!(Natural::parse(invalid).is_none());
+    }
+}
+
+#[test]
+fn addition() {
+    let tests = [
+        ("0", "0", "0"),
+        ("1", "0", "1"),
+        ("4294967295", "1", "4294967296"),
+        ("18446744073709551615", "1", "18446744073709551616"),
+        ("99999999999999999999", "1", "100000000000000000000"),
+        ("123456789123456789", "987654321987654321", "1111111111111111110"),
+    ];
+
+    for (lhs, rhs, expected) in tests {
+        assert_eq!(natural(lhs).add(&natural(rhs)), natural(expected));
+        assert_eq!(natural(rhs).add(&natural(lhs)), natural(expected));
+    }
+}
+
+#[test]
+fn monus() {
+    let tests = [
+        ("5", "3", Some("2")),
+        ("3", "5", None),
+        ("5", "5", Some("0")),
+        ("4294967296", "1", Some("4294967295")),
+        ("18446744073709551616", "18446744073709551615", Some("1")),
+        ("100000000000000000000", "1", Some("99999999999999999999")),
+        ("1", "100000000000000000000", None),
+    ];
+
+    for (lhs, rhs, expected) in tests {
+        assert_eq!(natural(lhs).checked_sub(&natural(rhs)), expected.map(natural));
+        assert_eq!(natural(lhs).monus(&natural(rhs)), natural(expected.unwrap_or("0")));
+    }
+}
+
+#[test]
+fn comparison() {
+    let tests = [
+        ("0", "0", Ordering::Equal),
+        ("0", "1", Ordering::Less),
+        ("4294967296", "4294967295", Ordering::Greater),
+        ("18446744073709551616", "18446744073709551616", Ordering::Equal),
+        ("18446744073709551617", "36893488147419103232", Ordering::Less),
+    ];
+
+    for (lhs, rhs, expected) in tests {
+        assert_eq!(natural(lhs).cmp(&natural(rhs)), expected);
+    }
+
+    assert_eq!(Natural::from(u64::MAX), natural("18446744073709551615"));
+    assert!(Natural::zero().is_zero());
+}
+
+#[test]
+fn multiplication_and_division() {
+    let tests = [
+        ("0", "12345", "0", "0"),
+        ("12345", "1", "12345", "0"),
+        ("4294967296", "4294967296", "18446744073709551616", "0"),
+        ("15511210043330985984000000", "25", "387780251083274649600000000", "0"),
+        ("123456789012345678901234567890", "987654321", "121932631124828532112482853211126352690", "0"),
+    ];
+
+    for (lhs, rhs, product, _) in tests {
+        assert_eq!(natural(lhs).mul(&natural(rhs)), natural(product));
+        assert_eq!(natural(rhs).mul(&natural(lhs)), natural(product));
+    }
+
+    let tests = [
+        ("17", "5", "3", "2"),
+        ("4", "5", "0", "4"),
+        ("18446744073709551616", "4294967296", "4294967296", "0"),
+        ("18446744073709551617", "18446744073709551616", "1", "1"),
+        ("121932631124828532112482853211126352691", "123456789012345678901234567890", "987654321", "1"),
+        ("121932631124828532111263526900955129811", "123456789012345678901234567890", "987654320", "123456789011126352591063345011"),
+        ("340282366920938463463374607431768211455", "18446744073709551617", "18446744073709551615", "0"),
+    ];
+
+    for (lhs, rhs, quotient, remainder) in tests {
+        assert_eq!(natural(lhs).div_rem(&natural(rhs)), Some((natural(quotient), natural(remainder))), "{} / {}", lhs, rhs);
+    }
+
+    assert_eq!(natural("5").div_rem(&Natural::zero()), None);
+}
@@ -1,13 +1,14 @@
-use while_interpreter::interpreter::executor_states::RunTime;
+use while_interpreter::interpreter::errors::ErrorKind;
+use while_interpreter::interpreter::executor_states::{ExecutionContext, NumericMode, RunTime, RunTimeOptions, UnderflowMode};
 use while_interpreter::interpreter::lexer::Lexer;
+use while_interpreter::interpreter::lexer::parser::Dialect;
 use while_interpreter::interpreter::lexer::scopes::TopLevelScope;
-use while_interpreter::interpreter::models::CodeLine;
+use while_interpreter::interpreter::models::{CodeLine, Value};
 use while_interpreter::interpreter::normalize;
-use while_interpreter::interpreter::utils::interpreter_watcher::{pseudo_status};
-use while_interpreter::interpreter::utils::interpreter_watcher::pseudo_status::get_status;
 use while_interpreter::interpreter::utils::logging::Logger;
 use while_interpreter::interpreter::utils::logging::Logger::{NoLogger};
-use crate::code_line_gen::{gen_code_line, gen_code_line_and_reset};
+use while_interpreter::interpreter::utils::output::BufferedOutput;
+use crate::code_line_gen::{gen_code_line, gen_code_line_and_reset, gen_code_lines};
 
 
 struct StringValuePair<T> {
@@ -70,21 +71,73 @@ fn variable_set() {
 
     for test in tests {
         let tokenizer = Lexer::new(NoLogger);
-        let scope: TopLevelScope = tokenizer.tokenize(test.code_lines.clone());
+        let scope: TopLevelScope = tokenizer.tokenize(test.code_lines.clone()).unwrap();
 
-        if pseudo_status::get_status() {
-            println!("{}", pseudo_status::get_message());
-            return;
+        let mut run_time = RunTime::new(scope, NoLogger);
+        run_time.run().unwrap();
+
+        for result in &test.results {
+            let actual_result = run_time.get_value_from_current_name(&result.variable_name);
+            let expected = result.value;
+
+            assert_eq!(actual_result.unwrap(), Value::from(expected));
         }
+    }
+}
+
+#[test]
+fn eager_assignment() {
+    let tests = [
+        CodeLineStackPair {
+            code_lines: vec![
+                CodeLine::new("y = 1;", 1),
+                CodeLine::new("x = y;", 2),
+                CodeLine::new("y += 1;", 3)
+            ],
+            results: vec![
+                StringValuePair::<u32> {
+                    variable_name: String::from("x"),
+                    value: 1
+                },
+                StringValuePair::<u32> {
+                    variable_name: String::from("y"),
+                    value: 2
+                }
+            ]
+        },
+        CodeLineStackPair {
+            code_lines: vec![
+                CodeLine::new("num Inc(a):", 1),
+                CodeLine::new("    a += 1;", 2),
+                CodeLine::new("    return a;", 3),
+                CodeLine::new("y = 5;", 4),
+                CodeLine::new("x = Inc(y);", 5),
+                CodeLine::new("y = 0;", 6)
+            ],
+            results: vec![
+                StringValuePair::<u32> {
+                    variable_name: String::from("x"),
+                    value: 6
+                },
+                StringValuePair::<u32> {
+                    variable_name: String::from("y"),
+                    value: 0
+                }
+            ]
+        }
+    ];
+
+    for test in tests {
+        let tokenizer = Lexer::new(NoLogger);
+        let scope: TopLevelScope = tokenizer.tokenize(test.code_lines.clone()).unwrap();
 
         let mut run_time = RunTime::new(scope, NoLogger);
-        run_time.run();
+        run_time.run().unwrap();
 
         for result in &test.results {
-            let actual_result = RunTime::get_value_from_current_name(&result.variable_name);
-            let expected = result.value;
+            let actual_result = run_time.get_value_from_current_name(&result.variable_name);
 
-            assert_eq!(actual_result, expected);
+            assert_eq!(actual_result.unwrap(), Value::from(result.value));
         }
     }
 }
@@ -196,31 +249,22 @@ fn method_call() {
     ];
 
     for test in tests {
-        pseudo_status::reset_status();
-        RunTime::reset();
-
         let source_code = normalize(&test.code_lines);
         let lexer = Lexer::new(NoLogger);
-        let scope: TopLevelScope = lexer.tokenize(source_code);
-
-        if pseudo_status::get_status() {
-            println!("Status failed: not continuing");
-            println!("{}", pseudo_status::get_message());
-            return;
-        }
+        let scope: TopLevelScope = lexer.tokenize(source_code).unwrap();
 
         let mut run_time = RunTime::new(scope, NoLogger);
-        run_time.run();
+        run_time.run().unwrap();
 
 
         for result in &test.results {
-            let actual_result = RunTime::get_value_from_current_name(&result.variable_name);
+            let actual_result = run_time.get_value_from_current_name(&result.variable_name);
             let expected = result.value;
 
-            if result.findable == true {
-                assert_eq!(actual_result, expected);
+            if result.findable {
+                assert_eq!(actual_result.unwrap(), Value::from(expected));
             } else {
-                assert!(get_status());
+                assert!(actual_result.is_err());
             }
         }
     }
@@ -338,32 +382,23 @@ fn while_return() {
     ];
 
     for test in tests {
-        pseudo_status::reset_status();
-        RunTime::reset();
-
         let source_code = normalize(&test.code_lines);
         let lexer = Lexer::new(Logger::NoLogger);
-        let scope: TopLevelScope = lexer.tokenize(source_code);
-
-        if pseudo_status::get_status() {
-            println!("Status failed: not continuing");
-            println!("{}", pseudo_status::get_message());
-            return;
-        }
+        let scope: TopLevelScope = lexer.tokenize(source_code).unwrap();
 
 
         let mut run_time = RunTime::new(scope, NoLogger);
-        run_time.run();
+        run_time.run().unwrap();
 
 
         for result in &test.results {
-            let actual_result = RunTime::get_value_from_current_name(&result.variable_name);
+            let actual_result = run_time.get_value_from_current_name(&result.variable_name);
             let expected = result.value;
 
-            if result.findable == true {
-                assert_eq!(actual_result, expected);
+            if result.findable {
+                assert_eq!(actual_result.unwrap(), Value::from(expected));
             } else {
-                assert!(get_status());
+                assert!(actual_result.is_err());
             }
         }
     }
@@ -425,33 +460,705 @@ fn factorial_recursive() {
     ];
 
     for test in tests {
-        pseudo_status::reset_status();
-        RunTime::reset();
-
         let source_code = normalize(&test.code_lines);
         let lexer = Lexer::new(NoLogger);
-        let scope: TopLevelScope = lexer.tokenize(source_code);
+        let scope: TopLevelScope = lexer.tokenize(source_code).unwrap();
+
+
+        let mut run_time = RunTime::new(scope, NoLogger);
+        run_time.run().unwrap();
+
 
-        if pseudo_status::get_status() {
-            println!("Status failed: not continuing");
-            println!("{}", pseudo_status::get_message());
-            return;
+        for result in &test.results {
+            let actual_result = run_time.get_value_from_current_name(&result.variable_name);
+            let expected = result.value;
+
+            if result.findable {
+                assert_eq!(actual_result.unwrap(), Value::from(expected));
+            } else {
+                assert!(actual_result.is_err());
+            }
         }
+    }
+}
+#[test]
+fn fibonacci_recursive() {
+    let fib = [
+        "num Fib(n):",
+        "    result = n;",
+        "    isBig = 0;",
+        "    check = n;",
+        "    while check != 0:",
+        "        check -= 1;",
+        "        while check != 0:",
+        "            isBig = 1;",
+        "            check = 0;",
+        "        #",
+        "    #",
+        "    while isBig != 0:",
+        "        a = n;",
+        "        a -= 1;",
+        "        b = n;",
+        "        b -= 2;",
+        "        result = Fib(a);",
+        "        result += Fib(b);",
+        "        isBig = 0;",
+        "    #",
+        "    return result;",
+    ];
+
+    let tests = [(0, 0), (1, 1), (2, 1), (5, 5), (10, 55), (15, 610)];
 
+    for (n, expected) in tests {
+        let mut lines = fib.to_vec();
+        let call = format!("x = Fib({});", n);
+        lines.push(&call);
+
+        let code_lines = gen_code_lines(&lines);
+        let scope: TopLevelScope = Lexer::new(NoLogger).tokenize(code_lines).unwrap();
 
         let mut run_time = RunTime::new(scope, NoLogger);
-        run_time.run();
+        run_time.run().unwrap();
 
+        assert_eq!(run_time.get_value_from_current_name("x").unwrap(), Value::from(expected));
+    }
+}
+
+#[test]
+fn globals_and_locals() {
+    let tests = [
+        // globals can be read in methods
+        FindableCodeLineStackPair {
+            code_lines: vec![
+                CodeLine::new("num AddOffset(a):", 1),
+                CodeLine::new("    a += offset;", 2),
+                CodeLine::new("    return a;", 3),
+                CodeLine::new("offset = 10;", 4),
+                CodeLine::new("x = AddOffset(5);", 5)
+            ],
+            results: vec![
+                FindableStringValuePair::<u32> { variable_name: String::from("x"), value: 15, findable: true },
+                FindableStringValuePair::<u32> { variable_name: String::from("a"), value: 0, findable: false }
+            ]
+        },
+        // "=" assigns a local that shadows the global
+        FindableCodeLineStackPair {
+            code_lines: vec![
+                CodeLine::new("num Shadow():", 1),
+                CodeLine::new("    g = 1;", 2),
+                CodeLine::new("    return g;", 3),
+                CodeLine::new("g = 7;", 4),
+                CodeLine::new("x = Shadow();", 5)
+            ],
+            results: vec![
+                FindableStringValuePair::<u32> { variable_name: String::from("x"), value: 1, findable: true },
+                FindableStringValuePair::<u32> { variable_name: String::from("g"), value: 7, findable: true }
+            ]
+        },
+        // the locals of the caller aren't visible in the called method
+        FindableCodeLineStackPair {
+            code_lines: vec![
+                CodeLine::new("num Inner():", 1),
+                CodeLine::new("    return local;", 2),
+                CodeLine::new("num Outer():", 3),
+                CodeLine::new("    local = 3;", 4),
+                CodeLine::new("    r = Inner();", 5),
+                CodeLine::new("    return r;", 6),
+                CodeLine::new("x = Outer();", 7)
+            ],
+            results: vec![
+                FindableStringValuePair::<u32> { variable_name: String::from("x"), value: 0, findable: false }
+            ]
+        }
+    ];
+
+    for test in tests {
+        let scope: TopLevelScope = Lexer::new(NoLogger).tokenize(test.code_lines).unwrap();
+
+        let mut run_time = RunTime::new(scope, NoLogger);
+        let run_result = run_time.run();
 
         for result in &test.results {
-            let actual_result = RunTime::get_value_from_current_name(&result.variable_name);
-            let expected = result.value;
+            let actual_result = run_time.get_value_from_current_name(&result.variable_name);
 
-            if result.findable == true {
-                assert_eq!(actual_result, expected);
+            if result.findable {
+                assert_eq!(actual_result.unwrap(), Value::from(result.value));
             } else {
-                assert!(get_status());
+                assert!(actual_result.is_err());
             }
         }
+
+        if test.results.iter().all(|result| result.findable) {
+            assert!(run_result.is_ok());
+        }
+    }
+}
+
+#[test]
+fn method_call_statements() {
+    let tests = [
+        CodeLineStackPair {
+            code_lines: vec![
+                CodeLine::new("void Bump():", 1),
+                CodeLine::new("    counter += 1;", 2),
+                CodeLine::new("    return;", 3),
+                CodeLine::new("counter = 0;", 4),
+                CodeLine::new("n = 3;", 5),
+                CodeLine::new("while n != 0:", 6),
+                CodeLine::new("    Bump();", 7),
+                CodeLine::new("    n -= 1;", 8),
+                CodeLine::new("#", 9)
+            ],
+            results: vec![
+                StringValuePair::<u32> {
+                    variable_name: String::from("counter"),
+                    value: 3
+                }
+            ]
+        },
+        CodeLineStackPair {
+            code_lines: vec![
+                CodeLine::new("void Bump():", 1),
+                CodeLine::new("    counter += 1;", 2),
+                CodeLine::new("    return;", 3),
+                CodeLine::new("void BumpTwice():", 4),
+                CodeLine::new("    Bump();", 5),
+                CodeLine::new("    Bump();", 6),
+                CodeLine::new("    return;", 7),
+                CodeLine::new("counter = 0;", 8),
+                CodeLine::new("BumpTwice();", 9)
+            ],
+            results: vec![
+                StringValuePair::<u32> {
+                    variable_name: String::from("counter"),
+                    value: 2
+                }
+            ]
+        }
+    ];
+
+    for test in tests {
+        let tokenizer = Lexer::new(NoLogger);
+        let scope: TopLevelScope = tokenizer.tokenize(test.code_lines.clone()).unwrap();
+
+        let mut run_time = RunTime::new(scope, NoLogger);
+        run_time.run().unwrap();
+
+        for result in &test.results {
+            let actual_result = run_time.get_value_from_current_name(&result.variable_name);
+
+            assert_eq!(actual_result.unwrap(), Value::from(result.value));
+        }
+    }
+}
+
+#[test]
+fn writeln_output() {
+    let tests = [
+        (vec![
+            "x = 5;",
+            "writeln(x);",
+            "writeln(7);",
+        ], vec!["5", "7"]),
+        (vec![
+            "num Add(x, y):",
+            "    writeln(x);",
+            "    x += y;",
+            "    return x;",
+            "n = 3;",
+            "while n != 0:",
+            "    writeln(n);",
+            "    n -= 1;",
+            "#",
+            "writeln(Add(1, 2));",
+        ], vec!["3", "2", "1", "1", "3"]),
+    ];
+
+    for (lines, expected) in tests {
+        let code_lines = gen_code_lines(&lines);
+        let scope: TopLevelScope = Lexer::new(NoLogger).tokenize(code_lines).unwrap();
+
+        let output = BufferedOutput::new();
+        let mut run_time = RunTime::with_output(scope, NoLogger, Box::new(output.clone()));
+        run_time.run().unwrap();
+
+        assert_eq!(output.lines(), expected);
+    }
+}
+
+#[test]
+fn subtraction_underflow() {
+    let tests = [
+        (vec!["x = 0;", "x -= 1;"], 0),
+        (vec!["x = 3;", "x -= 5;"], 0),
+        (vec!["x = 5;", "x -= 3;"], 2),
+        (vec!["x = 5;", "x -= 5;"], 0),
+    ];
+
+    for (lines, expected) in tests {
+        let code_lines: Vec<CodeLine> = gen_code_lines(&lines);
+
+        let scope: TopLevelScope = Lexer::new(NoLogger).tokenize(code_lines.clone()).unwrap();
+        let mut run_time = RunTime::new(scope, NoLogger);
+        run_time.run().unwrap();
+
+        assert_eq!(run_time.get_value_from_current_name("x").unwrap(), Value::from(expected));
+
+        let options = RunTimeOptions { underflow: UnderflowMode::Error, ..Default::default() };
+        let scope: TopLevelScope = Lexer::new(NoLogger).tokenize(code_lines).unwrap();
+        let mut run_time = RunTime::with_options(scope, NoLogger, Box::new(BufferedOutput::new()), options);
+        let result = run_time.run();
+
+        if lines[0] == "x = 5;" {
+            assert_eq!(run_time.get_value_from_current_name("x").unwrap(), Value::from(expected));
+        } else {
+            assert_eq!(result.err().unwrap().kind, ErrorKind::Underflow);
+        }
+    }
+}
+
+#[test]
+fn parallel_programs() {
+    let handles = (1..=4u32).map(|n| {
+        std::thread::spawn(move || {
+            let code_lines = vec![
+                CodeLine::new(&format!("x = {};", n), 1),
+                CodeLine::new("counter = 100;", 2),
+                CodeLine::new("while counter != 0:", 3),
+                CodeLine::new("    x += 1;", 4),
+                CodeLine::new("    counter -= 1;", 5),
+                CodeLine::new("#", 6),
+            ];
+
+            let lexer = Lexer::new(NoLogger);
+            let scope: TopLevelScope = lexer.tokenize(code_lines).unwrap();
+
+            let mut run_time = RunTime::new(scope, NoLogger);
+            run_time.run().unwrap();
+
+            (n, run_time.get_value_from_current_name("x").unwrap())
+        })
+    }).collect::<Vec<_>>();
+
+    for handle in handles {
+        let (n, x) = handle.join().unwrap();
+        assert_eq!(x, Value::from(n + 100));
     }
-}
\ No newline at end of file
+}
+
+fn assert_send<T: Send>() {}
+
+#[test]
+fn run_time_on_another_thread() {
+    assert_send::<RunTime>();
+    assert_send::<ExecutionContext>();
+
+    let code_lines = vec![
+        CodeLine::new("num Twice(a):", 1),
+        CodeLine::new("    return a * 2;", 2),
+        CodeLine::new("x = Twice(21);", 3),
+        CodeLine::new("writeln(x);", 4),
+    ];
+
+    let output = BufferedOutput::new();
+    let scope: TopLevelScope = Lexer::new(NoLogger).tokenize(code_lines).unwrap();
+    let mut run_time = RunTime::with_output(scope, NoLogger, Box::new(output.clone()));
+
+    let run_time = std::thread::spawn(move || {
+        run_time.run().unwrap();
+        run_time
+    }).join().unwrap();
+
+    assert_eq!(run_time.get_value_from_current_name("x").unwrap(), Value::from(42u32));
+    assert_eq!(output.lines(), ["42"]);
+}
+
+#[test]
+fn big_numbers() {
+    let mul = [
+        "num Mul(a, b):",
+        "    result = 0;",
+        "    counter = b;",
+        "    while counter != 0:",
+        "        result += a;",
+        "        counter -= 1;",
+        "    #",
+        "    return result;",
+        "num IsZero(x):",
+        "    while x != 0:",
+        "        return 0;",
+        "    #",
+        "    return 1;",
+    ];
+
+    let tests = [
+        (vec!["x = 99999999999999999999;", "x += 1;"], "100000000000000000000"),
+        (vec!["x = 4294967295;", "x += 4294967295;", "x -= 4294967296;"], "4294967294"),
+        (vec![
+            "num Factorial(n):",
+            "    result = 1;",
+            "    counter = 0;",
+            "    rest = n;",
+            "    while rest != 0:",
+            "        counter += 1;",
+            "        result = Mul(result, counter);",
+            "        rest -= 1;",
+            "    #",
+            "    return result;",
+            "x = Factorial(25);",
+        ], "15511210043330985984000000"),
+        (vec![
+            "num Ackermann(m, n):",
+            "    mIsZero = IsZero(m);",
+            "    while mIsZero != 0:",
+            "        n += 1;",
+            "        return n;",
+            "    #",
+            "    previous = m;",
+            "    previous -= 1;",
+            "    nIsZero = IsZero(n);",
+            "    while nIsZero != 0:",
+            "        result = Ackermann(previous, 1);",
+            "        return result;",
+            "    #",
+            "    n -= 1;",
+            "    inner = Ackermann(m, n);",
+            "    result = Ackermann(previous, inner);",
+            "    return result;",
+            "x = Ackermann(2, 3);",
+            "y = Ackermann(3, 3);",
+            "x += y;",
+        ], "70"),
+    ];
+
+    for (lines, expected) in tests {
+        let code_lines = gen_code_lines(&[&mul[..], &lines[..]].concat());
+
+        let scope: TopLevelScope = Lexer::new(NoLogger).tokenize(code_lines).unwrap();
+        let mut run_time = RunTime::new(scope, NoLogger);
+        run_time.run().unwrap();
+
+        assert_eq!(run_time.get_value_from_current_name("x").unwrap().to_string(), expected);
+    }
+}
+
+#[test]
+fn numeric_modes() {
+    let add = ["x = 250;", "x += 10;"];
+    let sub = ["x = 3;", "x -= 5;"];
+    let literal = ["x = 300;"];
+    let big = ["x = 18446744073709551615;", "x += 1;"];
+
+    let tests = [
+        ("natural", &add[..], Ok("260")),
+        ("natural", &sub[..], Ok("0")),
+        ("natural", &big[..], Ok("18446744073709551616")),
+        ("u8-wrap", &add[..], Ok("4")),
+        ("u8-wrap", &sub[..], Ok("254")),
+        ("u8-wrap", &literal[..], Ok("44")),
+        ("u8-saturate", &add[..], Ok("255")),
+        ("u8-saturate", &sub[..], Ok("0")),
+        ("u8-saturate", &literal[..], Ok("255")),
+        ("u8-trap", &add[..], Err(ErrorKind::Overflow)),
+        ("u8-trap", &sub[..], Err(ErrorKind::Overflow)),
+        ("u8-trap", &literal[..], Err(ErrorKind::Overflow)),
+        ("u16-trap", &literal[..], Ok("300")),
+        ("u16-wrap", &sub[..], Ok("65534")),
+        ("u32-wrap", &sub[..], Ok("4294967294")),
+        ("u64-wrap", &big[..], Ok("0")),
+        ("u64-saturate", &big[..], Ok("18446744073709551615")),
+        ("u64-trap", &big[..], Err(ErrorKind::Overflow)),
+        ("i64-trap", &sub[..], Ok("-2")),
+        ("i64-wrap", &big[..], Ok("0")),
+        ("i64-saturate", &big[..], Ok("9223372036854775807")),
+        ("i64-wrap", &["x = 9223372036854775807;", "x += 1;"][..], Ok("-9223372036854775808")),
+    ];
+
+    for (mode, lines, expected) in tests {
+        let code_lines: Vec<CodeLine> = gen_code_lines(lines);
+        let options = RunTimeOptions { numeric: NumericMode::analyse(mode).unwrap(), ..Default::default() };
+
+        let scope: TopLevelScope = Lexer::new(NoLogger).tokenize(code_lines).unwrap();
+        let mut run_time = RunTime::with_options(scope, NoLogger, Box::new(BufferedOutput::new()), options);
+
+        let actual = run_time.run().map(|_| run_time.get_value_from_current_name("x").unwrap().to_string());
+
+        assert_eq!(actual.as_deref().map_err(|error| error.kind), expected, "{} {:?}", mode, lines);
+    }
+
+    for invalid in ["u7-wrap", "u8", "i64-clamp", "u8-wrap-trap"] {
+        assert!(NumericMode::analyse(invalid).is_none());
+    }
+
+    assert_eq!(NumericMode::analyse("u32-saturate").unwrap().to_string(), "u32-saturate");
+}
+
+#[test]
+fn extended_conditions() {
+    let tests = [
+        ("x < 5", 5),
+        ("x <= 5", 6),
+        ("x != 7", 7),
+        ("not x >= 3", 3),
+        ("x < 10 and x != 4", 4),
+        ("x < 2 or x == 2 or x == 3", 4),
+        ("not (x == 6 or x > 8)", 6),
+        ("Double(x) < 9", 5),
+        ("x == 3 and Missing() == 1", 0),
+    ];
+
+    for (condition, expected) in tests {
+        let lines = [
+            "num Double(a):".to_string(),
+            "    a += a;".to_string(),
+            "    return a;".to_string(),
+            "x = 0;".to_string(),
+            format!("while {}:", condition),
+            "    x += 1;".to_string(),
+            "#".to_string(),
+        ];
+        let code_lines: Vec<CodeLine> = gen_code_lines(&lines);
+
+        let scope: TopLevelScope = Lexer::with_dialect(NoLogger, Dialect::Extended).tokenize(code_lines).unwrap();
+        let mut run_time = RunTime::new(scope, NoLogger);
+        run_time.run().unwrap();
+
+        assert_eq!(run_time.get_value_from_current_name("x").unwrap(), Value::from(expected), "{}", condition);
+    }
+}
+
+#[test]
+fn if_else() {
+    let lines = [
+        "num Max(a, b):",
+        "    if a > b:",
+        "        return a;",
+        "    # else:",
+        "        return b;",
+        "    #",
+        "    return 0;",
+        "num Sign(a):",
+        "    if a == 0:",
+        "        return 0;",
+        "    #",
+        "    return 1;",
+        "x = Max(3, 7);",
+        "y = Max(9, 2);",
+        "z = Sign(0);",
+        "w = Sign(5);",
+        "v = 0;",
+        "if v == 0:",
+        "    v = 10;",
+        "# else:",
+        "    v = 20;",
+        "#",
+        "if not v == 10:",
+        "    v += 1;",
+        "#",
+    ];
+
+    let code_lines: Vec<CodeLine> = gen_code_lines(&lines);
+    let scope: TopLevelScope = Lexer::with_dialect(NoLogger, Dialect::Extended).tokenize(code_lines).unwrap();
+    let mut run_time = RunTime::new(scope, NoLogger);
+    run_time.run().unwrap();
+
+    for (name, expected) in [("x", 7), ("y", 9), ("z", 0), ("w", 1), ("v", 10)] {
+        assert_eq!(run_time.get_value_from_current_name(name).unwrap(), Value::from(expected), "{}", name);
+    }
+}
+
+#[test]
+fn loop_fixed_iterations() {
+    let tests = [
+        (vec!["n = 3;", "x = 0;", "loop n:", "    x += 1;", "#"], 3),
+        (vec!["n = 3;", "x = 0;", "loop n:", "    x += 1;", "    n += 1;", "#"], 3),
+        (vec!["n = 5;", "x = 0;", "loop n:", "    x += 1;", "    n = 0;", "#"], 5),
+        (vec!["n = 0;", "x = 7;", "loop n:", "    x += 1;", "#"], 7),
+        (vec!["n = 3;", "x = 0;", "loop n:", "    loop n:", "        x += 1;", "    #", "#"], 9),
+    ];
+
+    for (lines, expected) in tests {
+        let code_lines: Vec<CodeLine> = gen_code_lines(&lines);
+
+        let scope: TopLevelScope = Lexer::with_dialect(NoLogger, Dialect::Loop).tokenize(code_lines).unwrap();
+        let mut run_time = RunTime::new(scope, NoLogger);
+        run_time.run().unwrap();
+
+        assert_eq!(run_time.get_value_from_current_name("x").unwrap(), Value::from(expected), "{:?}", lines);
+    }
+}
+
+#[test]
+fn arithmetic_expressions() {
+    let tests = [
+        ("1 + 2 * 3", Ok("7")),
+        ("(1 + 2) * 3", Ok("9")),
+        ("10 - 4 - 3", Ok("3")),
+        ("10 - (4 - 3)", Ok("9")),
+        ("3 - 10 + 2", Ok("2")),
+        ("17 / 5 + 17 % 5", Ok("5")),
+        ("y * y - 1", Ok("15")),
+        ("Double(y + 1) * 2", Ok("20")),
+        ("Double(Double(1) + y) % 5", Ok("2")),
+        ("99999999999999999999 * 99999999999999999999", Ok("9999999999999999999800000000000000000001")),
+        ("y / (y - 4)", Err(ErrorKind::DivisionByZero)),
+        ("y % 0", Err(ErrorKind::DivisionByZero)),
+    ];
+
+    for (expression, expected) in tests {
+        let lines = [
+            "num Double(a):".to_string(),
+            "    return a + a;".to_string(),
+            "y = 4;".to_string(),
+            format!("x = {};", expression),
+        ];
+        let code_lines: Vec<CodeLine> = gen_code_lines(&lines);
+
+        let scope: TopLevelScope = Lexer::new(NoLogger).tokenize(code_lines).unwrap();
+        let mut run_time = RunTime::new(scope, NoLogger);
+        let actual = run_time.run().map(|_| run_time.get_value_from_current_name("x").unwrap().to_string());
+
+        assert_eq!(actual.as_deref().map_err(|error| error.kind), expected, "{}", expression);
+    }
+
+    let lines = [
+        "x = 0;",
+        "y = 3;",
+        "x += y * 2;",
+        "while x - y * 2 < 3:",
+        "    x += 2 - 1;",
+        "#",
+        "writeln(x % 4 * (y + 1));",
+    ];
+    let code_lines: Vec<CodeLine> = gen_code_lines(&lines);
+    let output = BufferedOutput::new();
+
+    let scope: TopLevelScope = Lexer::with_dialect(NoLogger, Dialect::Extended).tokenize(code_lines).unwrap();
+    let mut run_time = RunTime::with_output(scope, NoLogger, Box::new(output.clone()));
+    run_time.run().unwrap();
+
+    assert_eq!(output.lines(), ["4"]);
+
+    let tests = [
+        ("u8-wrap", "16 * 17", Ok("16")),
+        ("u8-saturate", "16 * 17", Ok("255")),
+        ("u8-trap", "16 * 17", Err(ErrorKind::Overflow)),
+        ("u64-wrap", "4294967296 * 4294967296 * 4294967296", Ok("0")),
+        ("i64-saturate", "(0 - 9223372036854775807) * 9223372036854775807 * 2", Ok("-9223372036854775808")),
+        ("i64-trap", "(0 - 7) / 2", Ok("-3")),
+        ("i64-trap", "(0 - 7) % 2", Ok("-1")),
+        ("u8-trap", "7 / 0", Err(ErrorKind::DivisionByZero)),
+    ];
+
+    for (mode, expression, expected) in tests {
+        let code_lines = vec![CodeLine::new(&format!("x = {};", expression), 1)];
+        let options = RunTimeOptions { numeric: NumericMode::analyse(mode).unwrap(), ..Default::default() };
+
+        let scope: TopLevelScope = Lexer::new(NoLogger).tokenize(code_lines).unwrap();
+        let mut run_time = RunTime::with_options(scope, NoLogger, Box::new(BufferedOutput::new()), options);
+        let actual = run_time.run().map(|_| run_time.get_value_from_current_name("x").unwrap().to_string());
+
+        assert_eq!(actual.as_deref().map_err(|error| error.kind), expected, "{} {}", mode, expression);
+    }
+}
+
+#[test]
+fn compound_assignments() {
+    let tests = [
+        ("natural", "x *= 6;", Ok("42")),
+        ("natural", "x /= 2;", Ok("3")),
+        ("natural", "x %= 4;", Ok("3")),
+        ("natural", "x ^= 3;", Ok("343")),
+        ("natural", "x ^= 0;", Ok("1")),
+        ("natural", "x ^= 40;", Ok("6366805760909027985741435139224001")),
+        ("natural", "x *= 2 ^ 3 ^ 2;", Ok("3584")),
+        ("natural", "x /= 0;", Err(ErrorKind::DivisionByZero)),
+        ("natural", "x %= 7 - 7;", Err(ErrorKind::DivisionByZero)),
+        ("u8-wrap", "x *= 100;", Ok("188")),
+        ("u8-wrap", "x ^= 3;", Ok("87")),
+        ("u8-saturate", "x ^= 3;", Ok("255")),
+        ("u8-trap", "x ^= 3;", Err(ErrorKind::Overflow)),
+        ("u8-trap", "x ^= 2;", Ok("49")),
+        ("u64-wrap", "x ^= 18446744073709551615;", Ok("7905747460161236407")),
+        ("u32-trap", "x /= 0;", Err(ErrorKind::DivisionByZero)),
+        ("i64-trap", "x -= 10; x ^= 3;", Ok("-27")),
+        ("i64-saturate", "x -= 10; x ^= 41;", Ok("-9223372036854775808")),
+        ("i64-trap", "x ^= 0 - 1;", Ok("0")),
+        ("i64-trap", "x -= 8; x ^= 0 - 3;", Ok("-1")),
+        ("i64-trap", "x -= 7; x ^= 0 - 1;", Err(ErrorKind::DivisionByZero)),
+    ];
+
+    for (mode, statements, expected) in tests {
+        let source = format!("x = 7; {}", statements);
+        let code_lines = vec![CodeLine::new(&source, 1)];
+        let options = RunTimeOptions { numeric: NumericMode::analyse(mode).unwrap(), ..Default::default() };
+
+        let scope: TopLevelScope = Lexer::new(NoLogger).tokenize(code_lines).unwrap();
+        let mut run_time = RunTime::with_options(scope, NoLogger, Box::new(BufferedOutput::new()), options);
+        let actual = run_time.run().map(|_| run_time.get_value_from_current_name("x").unwrap().to_string());
+
+        assert_eq!(actual.as_deref().map_err(|error| error.kind), expected, "{} {}", mode, source);
+    }
+}
+
+#[test]
+fn break_and_continue() {
+    let tests = [
+        (vec!["i = 0;", "while i != 100:", "    i += 1;", "    if i == 7:", "        break;", "    #", "#"], "i", "7"),
+        (vec!["i = 0; s = 0;", "while i < 10:", "    i += 1;", "    if i % 2 == 0:", "        continue;", "    #", "    s += i;", "#"], "s", "25"),
+        (vec!["s = 0; n = 5;", "loop n:", "    s += 1;", "    continue;", "    s += 100;", "#"], "s", "5"),
+        (vec!["s = 0; n = 5;", "loop n:", "    if s == 2:", "        break;", "    #", "    s += 1;", "#"], "s", "2"),
+        (vec!["i = 3; s = 0;", "while i != 0:", "    i -= 1;", "    j = 5;", "    while j != 0:", "        j -= 1;", "        break;", "    #", "    s += j;", "#"], "s", "12"),
+        (vec!["num Find(n):", "    i = 0;", "    while 1 == 1:", "        if i * i >= n:", "            return i;", "        #", "        i += 1;", "    #", "    return 0;", "x = Find(50);"], "x", "8"),
+    ];
+
+    for (lines, name, expected) in tests {
+        let code_lines: Vec<CodeLine> = gen_code_lines(&lines);
+
+        let scope: TopLevelScope = Lexer::with_dialect(NoLogger, Dialect::Extended).tokenize(code_lines).unwrap();
+        let mut run_time = RunTime::new(scope, NoLogger);
+        run_time.run().unwrap();
+
+        assert_eq!(run_time.get_value_from_current_name(name).unwrap().to_string(), expected, "{:?}", lines);
+    }
+}
+
+#[test]
+fn step_log() {
+    let lines = [
+        "num Twice(a):",
+        "    return a * 2;",
+        "x = 2;",
+        "while x != 0:",
+        "    x -= 1;",
+        "    if x == 0:",
+        "        writeln(Twice(x + 3));",
+        "    #",
+        "#",
+    ];
+
+    let expected = [
+        "x = 2;  => 2",
+        "while x != 0:  => true",
+        "    x -= 1;  => 1",
+        "    if x == 0:  => false",
+        "while x != 0:  => true",
+        "    x -= 1;  => 0",
+        "    if x == 0:  => true",
+        "        writeln(Twice(x + 3));",
+        "        call Twice(3)",
+        "            return a * 2;  => 6",
+        "6",
+        "while x != 0:  => false",
+    ];
+
+    let code_lines: Vec<CodeLine> = gen_code_lines(&lines);
+    let scope: TopLevelScope = Lexer::with_dialect(NoLogger, Dialect::Extended).tokenize(code_lines).unwrap();
+
+    let output = BufferedOutput::new();
+    let mut run_time = RunTime::with_output(scope, NoLogger, Box::new(output.clone()));
+    run_time.context_mut().trace_to(Box::new(output.clone()));
+    run_time.run().unwrap();
+
+    assert_eq!(output.lines(), expected);
+}
@@ -0,0 +1,102 @@
+use while_interpreter::interpreter::executor_states::RunTimeOptions;
+use while_interpreter::interpreter::lexer::parser::Dialect;
+use while_interpreter::interpreter::repl::Repl;
+use while_interpreter::interpreter::utils::logging::Logger::NoLogger;
+use while_interpreter::interpreter::utils::output::BufferedOutput;
+
+fn session(dialect: Dialect) -> (Repl, BufferedOutput) {
+    let output = BufferedOutput::new();
+    let repl = Repl::new(NoLogger, dialect, RunTimeOptions::default(), Box::new(output.clone()));
+
+    return (repl, output);
+}
+
+#[test]
+fn state_across_inputs() {
+    let (mut repl, output) = session(Dialect::Extended);
+
+    let inputs = [
+        ("x = 3;", false),
+        ("num Twice(a):", true),
+        ("    if a > 100:", true),
+        ("        return a;", true),
+        ("    #", true),
+        ("    return a * 2;", false),
+        ("while x != 0:", true),
+        ("    // a comment", true),
+        ("    writeln(Twice(x));", true),
+        ("    x -= 1;", true),
+        ("#", false),
+        ("y = Twice(21);", false),
+    ];
+
+    for (line, pending) in inputs {
+        assert_eq!(repl.input(line), Vec::<String>::new(), "{}", line);
+        assert_eq!(repl.is_pending(), pending, "{}", line);
+    }
+
+    assert_eq!(output.lines(), ["6", "4", "2"]);
+    assert_eq!(repl.input(":vars"), ["x = 0", "y = 42"]);
+    assert_eq!(repl.input(":methods"), ["num Twice(a)"]);
+    assert_eq!(repl.input(":ast")[2], "│  ├── Method token: TWICE");
+
+    repl.input(":quit");
+    assert!(repl.is_closed());
+}
+
+#[test]
+fn errors_keep_the_session() {
+    let (mut repl, _) = session(Dialect::Classic);
+
+    let inputs = [
+        ("x = 1;", 0),
+        ("x = ;", 1),
+        ("y = Missing(x);", 1),
+        ("x += 4; z = x / 0; w = 1;", 1),
+        ("break;", 1),
+        ("void Twice():", 0),
+        ("    return;", 0),
+        ("void Twice():", 0),
+        ("    return;", 1),
+        (":nothing", 1),
+        (":load", 1),
+    ];
+
+    for (line, messages) in inputs {
+        assert_eq!(repl.input(line).len(), messages, "{}", line);
+    }
+
+    assert_eq!(repl.input(":vars"), ["x = 5"]);
+    assert_eq!(repl.input(":methods"), ["void Twice()"]);
+
+    assert_eq!(repl.input(":reset"), ["The session was reset"]);
+    assert_eq!(repl.input(":vars"), ["No variables"]);
+    assert_eq!(repl.input(":methods"), ["No methods"]);
+}
+
+#[test]
+fn macros_and_files() {
+    let (mut repl, output) = session(Dialect::Classic);
+
+    for line in ["macro! twice $x; = {", "    $x += $x;", "}", "a = 3;", "twice a;"] {
+        assert_eq!(repl.input(line), Vec::<String>::new(), "{}", line);
+    }
+
+    let path = std::env::temp_dir().join("while_interpreter_repl_load.while");
+    std::fs::write(&path, "num Square(n):\n    return n * n;\n\nb = Square(a);\nwriteln(b);\n").unwrap();
+
+    assert_eq!(repl.input(&format!(":load {}", path.display())), Vec::<String>::new());
+    assert_eq!(repl.input("twice b;"), Vec::<String>::new());
+    assert_eq!(repl.input(":vars"), ["a = 6", "b = 72"]);
+    assert_eq!(output.lines(), ["36"]);
+
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(repl.input(&format!(":load {}", path.display())).len(), 1);
+
+    for line in ["macro! copy $x to $y; = {", "    tmp = $x;", "    $y = tmp;", "}", "tmp_1 = 7;", "copy a to c;"] {
+        assert_eq!(repl.input(line), Vec::<String>::new(), "{}", line);
+    }
+
+    let variables = repl.input(":vars");
+    assert!(variables.contains(&"tmp_1 = 7".to_string()) && variables.contains(&"c = 6".to_string()), "{:?}", variables);
+}
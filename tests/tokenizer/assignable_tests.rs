@@ -1,6 +1,6 @@
 use while_interpreter::interpreter::models::CodeLine;
-use while_interpreter::interpreter::lexer::assignables::{DigitToken, NameToken};
-use while_interpreter::interpreter::lexer::methods::MethodCallToken;
+use while_interpreter::interpreter::lexer::assignables::DigitToken;
+use while_interpreter::interpreter::lexer::models::AssignableToken;
 
 #[test]
 fn name_token() {
@@ -11,14 +11,14 @@ fn name_token() {
         ("5a", false),
         ("^^a", false),
         ("while", false),
+        ("_tmp_1", true),
+        ("$a", false),
+        ("a$b", false),
     ];
 
     for pair in string_result_pair {
-        let token = NameToken::parse(pair.0);
-
-        if pair.1 {
-            assert_eq!(pair.0, token.unwrap().value)
-        }
+        let token = AssignableToken::parse(&CodeLine::new_from_line(pair.0));
+        assert_eq!(pair.1, matches!(token, Ok(Some(AssignableToken::Name { value })) if value.value == pair.0), "{}", pair.0)
     }
 }
 
@@ -43,8 +43,8 @@ fn method_call_token() {
     ];
 
     for pair in string_result_pair {
-        let token = MethodCallToken::parse(&CodeLine::new_from_line(pair.0));
-        assert_eq!(pair.1, token.is_some())
+        let token = AssignableToken::parse(&CodeLine::new_from_line(pair.0));
+        assert_eq!(pair.1, matches!(token, Ok(Some(AssignableToken::MethodCall { .. }))), "{}", pair.0)
     }
 }
 
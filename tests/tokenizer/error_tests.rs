@@ -0,0 +1,230 @@
+use while_interpreter::interpreter::analysis::Analyzer;
+use while_interpreter::interpreter::errors::ErrorKind;
+use while_interpreter::interpreter::executor_states::RunTime;
+use while_interpreter::interpreter::lexer::Lexer;
+use while_interpreter::interpreter::normalize;
+use while_interpreter::interpreter::utils::logging::Logger::NoLogger;
+use while_interpreter::interpreter::utils::output::BufferedOutput;
+use crate::code_line_gen::gen_code_lines;
+
+#[test]
+fn lexer_errors() {
+    let tests = [
+        (vec!["x = 5"], ErrorKind::ExpectedSemicolon, 1),
+        (vec!["x = 5;", "y += ;"], ErrorKind::ExpectedAssignable, 2),
+        (vec!["while x != 0:", "    x -= 1;"], ErrorKind::MissingEscapeToken, 1),
+        (vec!["while x != 1:", "#"], ErrorKind::UnexpectedToken, 1),
+        (vec!["void main()"], ErrorKind::ExpectedColon, 1),
+        (vec!["void main():", "    a = 5;"], ErrorKind::MissingReturn, 1),
+        (vec!["num main():", "    return;"], ErrorKind::InvalidReturn, 2),
+        (vec!["x = 1;", "break;"], ErrorKind::InvalidLoopControl, 2),
+        (vec!["void main():", "    if x != 0:", "        continue;", "    #", "    return;"], ErrorKind::InvalidLoopControl, 3),
+        (vec!["while x != 0:", "    break", "#"], ErrorKind::ExpectedSemicolon, 2),
+        (vec!["void main():", "void other():", "    return;"], ErrorKind::MissingReturn, 1),
+        (vec!["x = 1 +;"], ErrorKind::ExpectedAssignable, 1),
+        (vec!["x = (1 + 2;"], ErrorKind::UnbalancedParentheses, 1),
+        (vec!["x = 1 2;"], ErrorKind::ExpectedSemicolon, 1),
+        (vec!["x = * 2;"], ErrorKind::ExpectedAssignable, 1),
+        (vec!["$x = 1;"], ErrorKind::UnexpectedToken, 1),
+        (vec!["x$1 = 2;"], ErrorKind::UnexpectedToken, 1),
+        (vec!["x = 1;", "y = t$1;"], ErrorKind::ExpectedSemicolon, 2),
+    ];
+
+    for (lines, kind, line) in tests {
+        let errors = Lexer::new(NoLogger).tokenize(gen_code_lines(&lines)).err().unwrap();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, kind);
+        assert_eq!(errors[0].line, line);
+    }
+}
+
+#[test]
+fn lexer_recovery() {
+    let lines = [
+        "x = 5",
+        "y = 3;",
+        "void broken()",
+        "    a = 1;",
+        "    return;",
+        "num ok(a):",
+        "    b = a",
+        "    while b != 0:",
+        "        b -= ;",
+        "    #",
+        "    return b;",
+        "void unterminated():",
+        "    while y != 0:",
+        "        y -= 1;",
+        "    return;",
+        "while y != 0:",
+        "    y -= ;",
+        "    y -= 1;",
+        "#",
+        "z = 1;",
+        "num noReturn():",
+        "    c = 1;",
+        "w = 2",
+    ];
+
+    let expected = [
+        (1, ErrorKind::ExpectedSemicolon),
+        (3, ErrorKind::ExpectedColon),
+        (7, ErrorKind::ExpectedSemicolon),
+        (9, ErrorKind::ExpectedAssignable),
+        (12, ErrorKind::MissingReturn),
+        (13, ErrorKind::MissingEscapeToken),
+        (17, ErrorKind::ExpectedAssignable),
+        (21, ErrorKind::MissingReturn),
+        (23, ErrorKind::ExpectedSemicolon),
+    ];
+
+    let errors = Lexer::new(NoLogger).tokenize(gen_code_lines(&lines)).err().unwrap();
+    let actual = errors.iter().map(|error| (error.line, error.kind)).collect::<Vec<_>>();
+
+    assert_eq!(actual, expected);
+    assert!(errors.iter().all(|error| error.code_line.as_ref().is_some_and(|code_line| code_line.line == lines[(error.line - 1) as usize])));
+}
+
+#[test]
+fn method_error_messages() {
+    let tests = [
+        (vec!["num Fib(n):", "    n -= 1;"], "Method Fib must end with a return"),
+        (vec!["void main():"], "Method main can't be empty"),
+    ];
+
+    for (lines, message) in tests {
+        let errors = Lexer::new(NoLogger).tokenize(gen_code_lines(&lines)).err().unwrap();
+
+        assert_eq!(errors[0].message, message, "{:?}", lines);
+    }
+}
+
+#[test]
+fn multi_line_recovery() {
+    let tests = [
+        (vec!["x = 1 +", ";", "y = 2;"], vec![(2, 1, ErrorKind::ExpectedAssignable)]),
+        (vec!["x = (1 +", "    2", "y = 3;", "z = 4"], vec![(2, 6, ErrorKind::UnbalancedParentheses), (4, 6, ErrorKind::ExpectedSemicolon)]),
+        (vec!["x = 1 +", "while x != 0:", "    x -= 1;", "#"], vec![(2, 1, ErrorKind::ExpectedAssignable)]),
+        (vec!["void main():", "    a = 1 *", "    return;"], vec![(3, 5, ErrorKind::ExpectedAssignable)]),
+        (vec!["x = 1", "    + 2;", "y = x +", "    ;"], vec![(4, 5, ErrorKind::ExpectedAssignable)]),
+    ];
+
+    for (lines, expected) in tests {
+        let errors = Lexer::new(NoLogger).tokenize(gen_code_lines(&lines)).err().unwrap();
+        let actual = errors.iter().map(|error| (error.line, error.column, error.kind)).collect::<Vec<_>>();
+
+        assert_eq!(actual, expected, "{:?}", lines);
+    }
+}
+
+#[test]
+fn block_header_recovery() {
+    let tests = [
+        (vec!["x = 3; while x > 0: x -= 1; #"], vec![(1, 16, ErrorKind::UnexpectedToken)]),
+        (vec!["x = 3; while x > 0: x -= 1;"], vec![(1, 16, ErrorKind::UnexpectedToken), (1, 8, ErrorKind::MissingEscapeToken)]),
+        (vec!["while F(x) != 0:", "    x -= 1;"], vec![(1, 8, ErrorKind::UnexpectedToken), (1, 1, ErrorKind::MissingEscapeToken)]),
+        (vec!["if x > 0: x -= 1;"], vec![(1, 6, ErrorKind::UnexpectedToken), (1, 1, ErrorKind::MissingEscapeToken)]),
+        (vec!["loop 5: x += 1; #"], vec![(1, 6, ErrorKind::ExpectedName)]),
+    ];
+
+    for (lines, expected) in tests {
+        let errors = Lexer::new(NoLogger).tokenize(gen_code_lines(&lines)).err().unwrap();
+        let actual = errors.iter().map(|error| (error.line, error.column, error.kind)).collect::<Vec<_>>();
+
+        assert_eq!(actual, expected, "{:?}", lines);
+    }
+}
+
+#[test]
+fn normalized_source_locations() {
+    let lines = [
+        "// counts down",
+        "x = 5;",
+        "",
+        "while x != 0:",
+        "    // decrement",
+        "    x -= ;",
+        "#",
+        "",
+        "void main():",
+        "      y = 1",
+        "    return;",
+    ];
+
+    let expected = [
+        (6, 10, ErrorKind::ExpectedAssignable),
+        (10, 12, ErrorKind::ExpectedSemicolon),
+    ];
+
+    let errors = Lexer::new(NoLogger).tokenize(normalize(&gen_code_lines(&lines))).err().unwrap();
+    let actual = errors.iter().map(|error| (error.line, error.column, error.kind)).collect::<Vec<_>>();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn semantic_errors() {
+    let lines = [
+        "num Add(x, y):",
+        "    z = x;",
+        "    z += y;",
+        "    return z;",
+        "void Log(a):",
+        "    while a != 0:",
+        "        Missing(a);",
+        "        a -= 1;",
+        "    #",
+        "    return;",
+        "num Add(a):",
+        "    return a;",
+        "a = Add(1);",
+        "b = Add(1, Add(2, 3, 4));",
+        "Log(Unknown());",
+        "c = Add(Add(1, 2), 3);",
+    ];
+
+    let expected = [
+        (7, 9, ErrorKind::MethodNotFound),
+        (11, 1, ErrorKind::DuplicateMethod),
+        (13, 5, ErrorKind::WrongArgumentCount),
+        (14, 12, ErrorKind::WrongArgumentCount),
+        (15, 5, ErrorKind::MethodNotFound),
+    ];
+
+    let code_lines = gen_code_lines(&lines);
+    let scope = Lexer::new(NoLogger).tokenize(code_lines.clone()).unwrap();
+    let errors = Analyzer::analyse(&scope, &code_lines).err().unwrap();
+    let actual = errors.iter().map(|error| (error.line, error.column, error.kind)).collect::<Vec<_>>();
+
+    assert_eq!(actual, expected);
+
+    let lines = ["num Add(x, y):", "    return x;", "z = Add(1, Add(2, 3));"];
+    let code_lines = gen_code_lines(&lines);
+    let scope = Lexer::new(NoLogger).tokenize(code_lines.clone()).unwrap();
+
+    assert!(Analyzer::analyse(&scope, &code_lines).is_ok());
+}
+
+#[test]
+fn runtime_errors() {
+    let tests = [
+        (vec!["x = 1;", "x += y;"], ErrorKind::VariableNotFound, 2, 1),
+        (vec!["x += 1;"], ErrorKind::VariableNotFound, 1, 1),
+        (vec!["x = 2; y = 3;", "z = x + 4; Add(1, 2);"], ErrorKind::MethodNotFound, 2, 12),
+        (vec!["num Add(x, y):", "    return x;", "z = Add(1, 2, 3);"], ErrorKind::WrongArgumentCount, 3, 5),
+        (vec!["num Div(a, b):", "    c = a;", "    c /= b;", "    return c;", "x = Div(4, 2);", "y = Div(x, 0);"], ErrorKind::DivisionByZero, 3, 5),
+        (vec!["num Two():", "    return 2;", "x = 1;", "x = Two() %", "    (x - 1);"], ErrorKind::DivisionByZero, 4, 1),
+        (vec!["x = 3;", "while x != 0:", "    x -= 1;", "#", "while y != 0:", "#"], ErrorKind::VariableNotFound, 5, 1),
+        (vec!["x = 2;", "loop x:", "    writeln(x / (x - 2));", "#"], ErrorKind::DivisionByZero, 3, 5),
+    ];
+
+    for (lines, kind, line, column) in tests {
+        let scope = Lexer::new(NoLogger).tokenize(gen_code_lines(&lines)).unwrap();
+        let mut run_time = RunTime::with_output(scope, NoLogger, Box::new(BufferedOutput::new()));
+        let error = run_time.run().err().unwrap().with_source(&gen_code_lines(&lines));
+
+        assert_eq!((error.kind, error.line, error.column), (kind, line, column), "{:?}", lines);
+        assert_eq!(error.code_line.unwrap().line, lines[(line - 1) as usize]);
+    }
+}
@@ -0,0 +1,52 @@
+use while_interpreter::interpreter::errors::ErrorKind;
+use while_interpreter::interpreter::lexer::Lexer;
+use while_interpreter::interpreter::lexer::models::Stackable;
+use while_interpreter::interpreter::lexer::parser::Dialect;
+use while_interpreter::interpreter::utils::logging::Logger::NoLogger;
+use crate::code_line_gen::gen_code_lines;
+
+#[test]
+fn if_else_blocks() {
+    let tests = [
+        (vec!["if a != 0:", "    a += 1;", "#"], 1, false),
+        (vec!["if a != 0:", "    a += 1;", "# else:", "    a -= 1;", "#"], 1, true),
+        (vec!["if a != 0:", "#", "else:", "#"], 0, true),
+        (vec!["if a != 0:", "    if b != 0:", "        b -= 1;", "    #", "# else:", "    while a != 0:", "        a -= 1;", "    #", "#"], 1, true),
+    ];
+
+    for (lines, then_count, has_else) in tests {
+        let scope = Lexer::new(NoLogger).tokenize(gen_code_lines(&lines)).unwrap();
+
+        assert_eq!(scope.stack.len(), 1);
+
+        match &scope.stack[0] {
+            Stackable::IfToken { value } => {
+                assert_eq!(value.scope.stack.len(), then_count);
+                assert_eq!(value.else_scope.is_some(), has_else);
+            }
+            _ => panic!("Expected an if token")
+        }
+    }
+}
+
+#[test]
+fn if_errors() {
+    let tests = [
+        (vec!["if a != 0:", "    a += 1;"], ErrorKind::MissingEscapeToken, 1),
+        (vec!["if a != 0:", "    a += 1;", "# else:", "    a -= 1;"], ErrorKind::MissingEscapeToken, 3),
+        (vec!["if a != 0:", "# else", "    a -= 1;", "#"], ErrorKind::ExpectedColon, 2),
+        (vec!["if a != 0", "    a -= 1;", "#"], ErrorKind::ExpectedColon, 1),
+        (vec!["if a > 0:", "    a -= 1;", "#"], ErrorKind::UnexpectedToken, 1),
+        (vec!["else:", "    a -= 1;", "#"], ErrorKind::UnexpectedToken, 1),
+    ];
+
+    for (lines, kind, line) in tests {
+        let errors = Lexer::new(NoLogger).tokenize(gen_code_lines(&lines)).err().unwrap();
+
+        assert_eq!(errors[0].kind, kind, "{:?}", lines);
+        assert_eq!(errors[0].line, line, "{:?}", lines);
+    }
+
+    let lines = ["if a > 0 and b == 1:", "    a -= 1;", "#"];
+    assert!(Lexer::with_dialect(NoLogger, Dialect::Extended).tokenize(gen_code_lines(&lines)).is_ok());
+}
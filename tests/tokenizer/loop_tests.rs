@@ -0,0 +1,88 @@
+use while_interpreter::interpreter::analysis::Analyzer;
+use while_interpreter::interpreter::errors::ErrorKind;
+use while_interpreter::interpreter::lexer::Lexer;
+use while_interpreter::interpreter::lexer::models::Stackable;
+use while_interpreter::interpreter::lexer::parser::Dialect;
+use while_interpreter::interpreter::utils::logging::Logger::NoLogger;
+use crate::code_line_gen::gen_code_lines;
+
+#[test]
+fn loop_block() {
+    let lines = ["loop n:", "    x += 1;", "    loop x:", "        y += 1;", "    #", "#"];
+    let scope = Lexer::with_dialect(NoLogger, Dialect::Loop).tokenize(gen_code_lines(&lines)).unwrap();
+
+    match &scope.stack[..] {
+        [Stackable::LoopToken { value }] => {
+            assert_eq!(value.counter.value, "n");
+            assert_eq!(value.scope.stack.len(), 2);
+        }
+        _ => panic!("Expected a single loop token")
+    }
+
+    let tests = [
+        (vec!["loop n:", "    x += 1;"], ErrorKind::MissingEscapeToken, 1),
+        (vec!["loop:", "    x += 1;", "#"], ErrorKind::ExpectedName, 1),
+        (vec!["loop n", "    x += 1;", "#"], ErrorKind::ExpectedColon, 1),
+        (vec!["loop 5:", "    x += 1;", "#"], ErrorKind::ExpectedName, 1),
+    ];
+
+    for (lines, kind, line) in tests {
+        let errors = Lexer::new(NoLogger).tokenize(gen_code_lines(&lines)).err().unwrap();
+
+        assert_eq!(errors.len(), 1, "{:?}", lines);
+        assert_eq!((errors[0].kind, errors[0].line), (kind, line), "{:?}", lines);
+    }
+}
+
+#[test]
+fn loop_dialect() {
+    let lines = ["x = 1;", "loop x:", "#", "while x != 0:", "    x -= 1;", "#"];
+
+    assert!(Lexer::new(NoLogger).tokenize(gen_code_lines(&lines)).is_ok());
+
+    let errors = Lexer::with_dialect(NoLogger, Dialect::Loop).tokenize(gen_code_lines(&lines)).err().unwrap();
+    let actual = errors.iter().map(|error| (error.line, error.kind)).collect::<Vec<_>>();
+
+    assert_eq!(actual, [(4, ErrorKind::WhileInLoopProgram)]);
+
+    let lines = [
+        "num Fac(n):",
+        "    r = 1;",
+        "    if n != 0:",
+        "        m = n;",
+        "        m -= 1;",
+        "        r = Mul(n, Fac(m));",
+        "    #",
+        "    return r;",
+        "num Mul(a, b):",
+        "    r = 0;",
+        "    loop b:",
+        "        r += a;",
+        "    #",
+        "    return r;",
+        "num Even(n):",
+        "    if n != 0:",
+        "        n -= 1;",
+        "        r = Odd(n);",
+        "        return r;",
+        "    #",
+        "    return 1;",
+        "num Odd(n):",
+        "    if n != 0:",
+        "        n -= 1;",
+        "        r = Even(n);",
+        "        return r;",
+        "    #",
+        "    return 0;",
+        "x = Mul(2, 3);",
+    ];
+
+    let code_lines = gen_code_lines(&lines);
+    let scope = Lexer::with_dialect(NoLogger, Dialect::Loop).tokenize(code_lines.clone()).unwrap();
+    let errors = Analyzer::analyse_with_dialect(&scope, &code_lines, Dialect::Loop).err().unwrap();
+    let actual = errors.iter().map(|error| (error.line, error.kind)).collect::<Vec<_>>();
+
+    assert_eq!(actual, [(1, ErrorKind::Recursion), (15, ErrorKind::Recursion), (22, ErrorKind::Recursion)]);
+    assert!(errors[1].message.contains("Even -> Odd -> Even"));
+    assert!(Analyzer::analyse(&scope, &code_lines).is_ok());
+}
@@ -0,0 +1,98 @@
+use while_interpreter::interpreter::errors::ErrorKind;
+use while_interpreter::interpreter::executor_states::RunTime;
+use while_interpreter::interpreter::lexer::Lexer;
+use while_interpreter::interpreter::macros::MacroExpander;
+use while_interpreter::interpreter::utils::logging::Logger::NoLogger;
+use crate::code_line_gen::gen_code_lines;
+
+const MULTIPLY: [&str; 10] = [
+    "macro! $variable *= $factor; = {",
+    "    counter = $factor;",
+    "    summand = $variable;",
+    "    $variable = 0;",
+    "    while counter != 0:",
+    "        $variable += summand;",
+    "        counter -= 1;",
+    "    #",
+    "}",
+    "counter = 2;",
+];
+
+#[test]
+fn expansion() {
+    let mut lines = MULTIPLY.to_vec();
+    lines.extend(["b = 3;", "b *= counter + 1; writeln(b);"]);
+
+    let expected = [
+        (10, "counter = 2;"),
+        (11, "b = 3;"),
+        (12, "counter_1 = (counter + 1);"),
+        (12, "summand_1 = b;"),
+        (12, "b = 0;"),
+        (12, "while counter_1 != 0:"),
+        (12, "    b += summand_1;"),
+        (12, "    counter_1 -= 1;"),
+        (12, "#"),
+        (12, "writeln(b);"),
+    ];
+
+    let expanded = MacroExpander::expand(&gen_code_lines(&lines)).unwrap();
+    let actual = expanded.iter().map(|code_line| (code_line.line_number, code_line.line.as_str())).collect::<Vec<_>>();
+
+    assert_eq!(actual, expected);
+
+    let lines = ["x = 1;", "while x != 0:", "    x -= 1;", "#"];
+    assert_eq!(MacroExpander::expand(&gen_code_lines(&lines)).unwrap(), gen_code_lines(&lines));
+}
+
+#[test]
+fn hygienic_programs() {
+    let tests = [
+        (vec!["b = 3;", "b *= 4;"], "b", "12"),
+        (vec!["b = 3;", "b *= 0;"], "b", "0"),
+        (vec!["b = 3;", "b *= counter; b *= counter;"], "b", "12"),
+        (vec!["b = 3;", "b *= counter;"], "counter", "2"),
+        (vec!["counter *= 5;"], "counter", "10"),
+        (vec!["summand = 7;", "summand *= summand;"], "summand", "49"),
+        (vec!["num Square(x):", "    x *= x;", "    return x;", "b = Square(counter + 3);"], "b", "25"),
+        (vec!["b = 2;", "while b != 0:", "    b -= 1;", "    counter *= 3;", "#"], "counter", "18"),
+        (vec!["counter_1 = 5;", "b = 3;", "b *= 4;"], "counter_1", "5"),
+        (vec!["summand_1 = 5;", "summand_1 *= 4;"], "summand_1", "20"),
+    ];
+
+    for (statements, name, value) in tests {
+        let mut lines = MULTIPLY.to_vec();
+        lines.extend(statements.iter());
+
+        let scope = Lexer::new(NoLogger).tokenize(gen_code_lines(&lines)).unwrap();
+        let mut run_time = RunTime::new(scope, NoLogger);
+        run_time.run().unwrap();
+
+        assert_eq!(run_time.get_value_from_current_name(name).unwrap().to_string(), value, "{:?}", statements);
+    }
+}
+
+#[test]
+fn macro_errors() {
+    let tests = [
+        (vec!["macro $x; = {", "}"], ErrorKind::InvalidMacro, 1),
+        (vec!["macro! $x = {", "}"], ErrorKind::InvalidMacro, 1),
+        (vec!["macro! twice $x;", "}"], ErrorKind::InvalidMacro, 1),
+        (vec!["macro! ; = {", "}"], ErrorKind::InvalidMacro, 1),
+        (vec!["macro! $a $b; = {", "}"], ErrorKind::InvalidMacro, 1),
+        (vec!["macro! $a + $a; = {", "}"], ErrorKind::InvalidMacro, 1),
+        (vec!["macro! twice $x; = {", "    $x += $y;", "}"], ErrorKind::InvalidMacro, 2),
+        (vec!["macro! twice $x; = {", "    $x += $x;"], ErrorKind::InvalidMacro, 1),
+        (vec!["macro! twice $x; = {", "}", "macro! twice $y; = {", "}"], ErrorKind::InvalidMacro, 3),
+        (vec!["macro! forever $x; = {", "    forever $x;", "}", "x = 1;", "forever x;"], ErrorKind::MacroExpansion, 5),
+        (vec!["macro! twice $x; = {", "    $x += $x;", "}", "twice x y;"], ErrorKind::UnexpectedToken, 4),
+    ];
+
+    for (lines, kind, line) in tests {
+        let errors = Lexer::new(NoLogger).tokenize(gen_code_lines(&lines)).err().unwrap();
+
+        assert_eq!(errors.len(), 1, "{:?}", lines);
+        assert_eq!(errors[0].kind, kind, "{:?}", lines);
+        assert_eq!(errors[0].line, line, "{:?}", lines);
+    }
+}
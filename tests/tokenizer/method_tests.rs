@@ -2,6 +2,7 @@ use while_interpreter::interpreter::models::CodeLine;
 use while_interpreter::interpreter::lexer::methods::MethodHeaderToken;
 use while_interpreter::interpreter::lexer::Lexer;
 use while_interpreter::interpreter::utils::logging::Logger::NoLogger;
+use crate::code_line_gen::gen_code_lines;
 
 #[test]
 fn method_header() {
@@ -20,7 +21,7 @@ fn method_header() {
 
     for test in tests {
         let token = MethodHeaderToken::parse(&CodeLine::new_from_line(test.0));
-        assert_eq!(test.1, token.is_some());
+        assert_eq!(test.1, matches!(token, Ok(Some(_))));
     }
 }
 
@@ -64,9 +65,9 @@ fn complete_method() {
 
     for pair in tests {
         let tokenizer = Lexer::new(NoLogger);
-        let lines: Vec<CodeLine> = (pair.0 as Vec<&str>).iter().enumerate().map(|(i, l)| CodeLine::new(l, (i + 1) as u32)).collect();
+        let lines: Vec<CodeLine> = gen_code_lines(&pair.0);
         let scope = tokenizer.tokenize(lines);
 
-        assert_eq!(pair.1, scope.methods.len());
+        assert_eq!(pair.1, scope.map(|scope| scope.methods.len()).unwrap_or(0));
     }
 }
\ No newline at end of file
@@ -1,5 +1,10 @@
 pub mod assignable_tests;
+pub mod error_tests;
+pub mod if_tests;
+pub mod loop_tests;
+pub mod macro_tests;
 pub mod method_tests;
 pub mod operator_tests;
+pub mod parser_tests;
 pub mod scope_tests;
 pub mod while_tests;
\ No newline at end of file
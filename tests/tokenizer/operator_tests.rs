@@ -1,5 +1,5 @@
 use while_interpreter::interpreter::models::CodeLine;
-use while_interpreter::interpreter::lexer::operators::AdditiveOperatorToken;
+use while_interpreter::interpreter::lexer::operators::OperatorToken;
 
 fn get_tests(operator: &str) -> [(String, bool); 7]{
     let tests =  [
@@ -13,7 +13,7 @@ fn get_tests(operator: &str) -> [(String, bool); 7]{
     ];
 
     return tests.map(|s| {
-        (s.0.replace("#", operator), s.1.clone())
+        (s.0.replace("#", operator), s.1)
     });
 }
 
@@ -22,8 +22,8 @@ fn additive_operator() {
     let tests = get_tests("+");
 
     for test in tests {
-        let token = AdditiveOperatorToken::parse(&CodeLine::new_from_line(&test.0));
-        assert_eq!(test.1, token.is_some());
+        let token = OperatorToken::parse(&CodeLine::new_from_line(&test.0));
+        assert_eq!(test.1, matches!(token, Ok(Some(_))));
     }
 }
 
@@ -32,7 +32,20 @@ fn subtractive_operator() {
     let tests = get_tests("-");
 
     for test in tests {
-        let token = AdditiveOperatorToken::parse(&CodeLine::new_from_line(&test.0));
-        assert_eq!(test.1, token.is_some());
+        let token = OperatorToken::parse(&CodeLine::new_from_line(&test.0));
+        assert_eq!(test.1, matches!(token, Ok(Some(_))));
     }
-}
\ No newline at end of file
+}
+#[test]
+fn compound_operators() {
+    for operator in ["*", "/", "%", "^"] {
+        for test in get_tests(operator) {
+            let token = OperatorToken::parse(&CodeLine::new_from_line(&test.0));
+            assert_eq!(test.1, matches!(token, Ok(Some(_))), "{}", test.0);
+        }
+    }
+
+    for line in ["a =* 2;", "a ^ = 2;", "a **= 2;"] {
+        assert!(!matches!(OperatorToken::parse(&CodeLine::new_from_line(line)), Ok(Some(_))), "{}", line);
+    }
+}
@@ -0,0 +1,98 @@
+use while_interpreter::interpreter::lexer::Lexer;
+use while_interpreter::interpreter::lexer::models::Stackable;
+use while_interpreter::interpreter::lexer::scanner::{Keyword, Scanner, SourceTokenKind};
+use while_interpreter::interpreter::models::CodeLine;
+use while_interpreter::interpreter::utils::logging::Logger::NoLogger;
+use while_interpreter::interpreter::utils::logging::TreeViewElement;
+use crate::code_line_gen::gen_code_lines;
+
+#[test]
+fn scanner() {
+    let tokens = Scanner::scan(&gen_code_lines(&["while x!=0: // loop", "  y-=foo(1,b);#"]));
+
+    let expected = [
+        (SourceTokenKind::Keyword(Keyword::While), 1, 1),
+        (SourceTokenKind::Name("x".to_string()), 1, 7),
+        (SourceTokenKind::NotEqual, 1, 8),
+        (SourceTokenKind::Number("0".to_string()), 1, 10),
+        (SourceTokenKind::Colon, 1, 11),
+        (SourceTokenKind::Name("y".to_string()), 2, 3),
+        (SourceTokenKind::SubAssign, 2, 4),
+        (SourceTokenKind::Name("foo".to_string()), 2, 6),
+        (SourceTokenKind::LeftParen, 2, 9),
+        (SourceTokenKind::Number("1".to_string()), 2, 10),
+        (SourceTokenKind::Comma, 2, 11),
+        (SourceTokenKind::Name("b".to_string()), 2, 12),
+        (SourceTokenKind::RightParen, 2, 13),
+        (SourceTokenKind::Semicolon, 2, 14),
+        (SourceTokenKind::Hash, 2, 15),
+        (SourceTokenKind::End, 2, 16),
+    ];
+
+    let actual = tokens.into_iter().map(|token| (token.kind, token.line, token.column)).collect::<Vec<_>>();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn whitespace_independence() {
+    let expected = [
+        "x = 5;",
+        "num Add(a, b):",
+        "    a += b;",
+        "    return a;",
+        "while x != 0:",
+        "    x -= Add(x, 1);",
+        "#",
+    ];
+
+    let tests = [
+        vec!["x=5;", "num Add(a,b):", "a+=b;", "return a;", "while x!=0:", "x-=Add(x,1);", "#"],
+        vec!["x = 5 ;", "num Add ( a , b ) :", "    a += b ;", "    return a ;", "while x != 0 :", "    x -= Add ( x , 1 ) ;", "    #"],
+        vec!["x =", "5;", "num Add(a,", "b):", "a += b; return a;", "while x != 0: x -= Add(", "x, 1); #"],
+    ];
+
+    let expected_scope = Lexer::new(NoLogger).tokenize(gen_code_lines(&expected)).unwrap();
+
+    for test in tests {
+        let scope = Lexer::new(NoLogger).tokenize(gen_code_lines(&test)).unwrap();
+
+        assert_eq!(scope.to_tree_view(), expected_scope.to_tree_view());
+    }
+}
+
+#[test]
+fn short_lines() {
+    let tests = [
+        vec!["x"],
+        vec!["x ="],
+        vec!["return"],
+        vec!["while"],
+        vec!["num"],
+        vec!["x = 5; y"],
+    ];
+
+    for test in tests {
+        assert!(Lexer::new(NoLogger).tokenize(gen_code_lines(&test)).is_err());
+    }
+}
+
+#[test]
+fn writeln_statement() {
+    let tests = [
+        ("writeln(x);", true),
+        ("writeln(5);", true),
+        ("writeln(Add(1, x));", true),
+        ("writeln ( x ) ;", true),
+        ("writeln(x)", false),
+        ("writeln();", false),
+        ("writeln(x;", false),
+        ("writeln x;", false),
+        ("writeln(x, y);", false),
+    ];
+
+    for test in tests {
+        let scope = Lexer::new(NoLogger).tokenize(vec![CodeLine::new_from_line(test.0)]);
+        assert_eq!(test.1, scope.is_ok_and(|scope| matches!(scope.stack[..], [Stackable::WritelnToken { .. }])), "{}", test.0);
+    }
+}
@@ -1,10 +1,11 @@
-use while_interpreter::interpreter::models::CodeLine;
+use while_interpreter::interpreter::models::{CodeLine, SourceLocation};
 use while_interpreter::interpreter::lexer::methods::{MethodHeaderToken, ReturnToken};
 use while_interpreter::interpreter::lexer::models::{AssignableToken, Stackable};
-use while_interpreter::interpreter::lexer::operators::AdditiveOperatorToken;
+use while_interpreter::interpreter::lexer::operators::OperatorToken;
 use while_interpreter::interpreter::lexer::Lexer;
 use while_interpreter::interpreter::lexer::variables::VariableToken;
 use while_interpreter::interpreter::utils::logging::Logger::NoLogger;
+use crate::code_line_gen::gen_code_lines;
 
 #[test]
 fn inner_body() {
@@ -15,44 +16,45 @@ fn inner_body() {
             "    b = 5;",
             "    return;"
         ], 1, vec![
-            Stackable::VariableToken { value: VariableToken::parse(&CodeLine::new_from_line("a = 5;")).unwrap() },
-            Stackable::VariableToken { value: VariableToken::parse(&CodeLine::new_from_line("b = 5;")).unwrap() },
-            Stackable::ReturnToken { value: ReturnToken::new(MethodHeaderToken::parse(&CodeLine::new_from_line("void main():"))) }
+            Stackable::VariableToken { value: VariableToken::parse(&CodeLine::new("    a = 5;", 2)).unwrap().unwrap() },
+            Stackable::VariableToken { value: VariableToken::parse(&CodeLine::new("    b = 5;", 3)).unwrap().unwrap() },
+            Stackable::ReturnToken { value: ReturnToken::new(MethodHeaderToken::parse(&CodeLine::new_from_line("void main():")).unwrap(), SourceLocation::new(4, 5)) }
         ]),
         (vec![
             "void main():",
             "    a = 5;",
             "    b = 5;",
         ], 0, vec![
-            Stackable::VariableToken { value: VariableToken::parse(&CodeLine::new_from_line("a = 5;")).unwrap() },
-            Stackable::VariableToken { value: VariableToken::parse(&CodeLine::new_from_line("b = 5;")).unwrap() },
-            Stackable::ReturnToken { value: ReturnToken::new(MethodHeaderToken::parse(&CodeLine::new_from_line("void main():"))) }
+            Stackable::VariableToken { value: VariableToken::parse(&CodeLine::new("    a = 5;", 2)).unwrap().unwrap() },
+            Stackable::VariableToken { value: VariableToken::parse(&CodeLine::new("    b = 5;", 3)).unwrap().unwrap() },
+            Stackable::ReturnToken { value: ReturnToken::new(MethodHeaderToken::parse(&CodeLine::new_from_line("void main():")).unwrap(), SourceLocation::new(4, 5)) }
         ]),
         (vec![
             "void blubbi(b):",
             "    e += 5;",
             "    return e;"
         ], 1, vec![
-            Stackable::AdditiveOperatorToken { value: AdditiveOperatorToken::parse(&CodeLine::new_from_line("e += 5;")).unwrap() },
+            Stackable::OperatorToken { value: OperatorToken::parse(&CodeLine::new("    e += 5;", 2)).unwrap().unwrap() },
             Stackable::ReturnToken { value: ReturnToken {
-                header: MethodHeaderToken::parse(&CodeLine::new_from_line("void blubbi(b):")),
-                return_value: AssignableToken::parse(&CodeLine::new_from_line("e"))
+                header: MethodHeaderToken::parse(&CodeLine::new_from_line("void blubbi(b):")).unwrap(),
+                return_value: AssignableToken::parse(&CodeLine::new_from_line("e")).unwrap(),
+                location: SourceLocation::new(3, 5)
             }}
         ])
     ];
 
     for pair in tests {
         let tokenizer = Lexer::new(NoLogger);
-        let lines: Vec<CodeLine> = pair.0.iter().enumerate().map(|(i, l)| CodeLine::new(l, (i + 1) as u32)).collect();
+        let lines: Vec<CodeLine> = gen_code_lines(&pair.0);
         let scope = tokenizer.tokenize(lines);
+        let methods = scope.map(|scope| scope.methods).unwrap_or_default();
 
-
-        if scope.methods.len() > 0 {
-            for (index, current_stackable) in scope.methods[0].scope.stack.iter().enumerate() {
+        if !methods.is_empty() {
+            for (index, current_stackable) in methods[0].scope.stack.iter().enumerate() {
                 assert_eq!(*current_stackable, pair.2[index]);
             }
         }
 
-        assert_eq!(scope.methods.len(), pair.1);
+        assert_eq!(methods.len(), pair.1);
     }
 }
\ No newline at end of file
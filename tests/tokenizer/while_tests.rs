@@ -1,9 +1,10 @@
-use while_interpreter::interpreter::models::CodeLine;
+use while_interpreter::interpreter::models::{CodeLine, SourceLocation};
 use while_interpreter::interpreter::lexer::assignables::NameToken;
 use while_interpreter::interpreter::lexer::models::Stackable;
-use while_interpreter::interpreter::lexer::operators::AdditiveOperatorToken;
+use while_interpreter::interpreter::lexer::operators::OperatorToken;
 use while_interpreter::interpreter::lexer::scopes::InnerBodyScope;
 use while_interpreter::interpreter::lexer::Lexer;
+use while_interpreter::interpreter::lexer::parser::Dialect;
 use while_interpreter::interpreter::lexer::while_tokens::{WhileHeaderToken, WhileToken};
 use while_interpreter::interpreter::utils::logging::Logger::NoLogger;
 use while_interpreter::interpreter::utils::logging::TreeViewElement;
@@ -30,14 +31,12 @@ fn simple_while() {
             ],
             while_count: 1,
             stackable_func_calc: || {
-                let while_header_token = WhileHeaderToken {
-                    against_zero_variable: Some(NameToken::new("a"))
-                };
-                let mut while_token = WhileToken::new(while_header_token, None,vec![]);
-                while_token.scope = Some(InnerBodyScope::new(None, vec![]));
+                let while_header_token = WhileHeaderToken::against_zero(NameToken::new("a"));
+                let mut while_token = WhileToken::new(while_header_token, None, SourceLocation::new(1, 1));
+                while_token.scope = Some(InnerBodyScope::new(None));
                 while_token.scope.as_mut().unwrap().stack.push(
-                    Stackable::AdditiveOperatorToken {
-                        value: AdditiveOperatorToken::parse(&CodeLine::new_from_line("a += 1;")).unwrap()
+                    Stackable::OperatorToken {
+                        value: OperatorToken::parse(&CodeLine::new_from_line("a += 1;")).unwrap().unwrap()
                     });
 
                 return vec![Stackable::WhileToken { value: while_token.clone() }];
@@ -51,14 +50,12 @@ fn simple_while() {
             ],
             while_count: 1,
             stackable_func_calc: || {
-                let while_header_token = WhileHeaderToken {
-                    against_zero_variable: Some(NameToken::new("a"))
-                };
-                let mut while_token = WhileToken::new(while_header_token, None,vec![]);
-                while_token.scope = Some(InnerBodyScope::new(None, vec![]));
+                let while_header_token = WhileHeaderToken::against_zero(NameToken::new("a"));
+                let mut while_token = WhileToken::new(while_header_token, None, SourceLocation::new(1, 1));
+                while_token.scope = Some(InnerBodyScope::new(None));
                 while_token.scope.as_mut().unwrap().stack.push(
-                    Stackable::AdditiveOperatorToken {
-                        value: AdditiveOperatorToken::parse(&CodeLine::new_from_line("a += 1;")).unwrap()
+                    Stackable::OperatorToken {
+                        value: OperatorToken::parse(&CodeLine::new_from_line("a += 1;")).unwrap().unwrap()
                     });
 
                 return vec![Stackable::WhileToken { value: while_token.clone() }];
@@ -68,7 +65,7 @@ fn simple_while() {
 
     for test in tests {
         let tokenizer = Lexer::new(NoLogger);
-        let scope = tokenizer.tokenize(test.code_lines);
+        let scope = tokenizer.tokenize(test.code_lines).unwrap();
 
         if test.while_count > 0 {
             assert_eq!(scope.stack.first().unwrap().to_tree_view()[0], (test.stackable_func_calc)().first().unwrap().to_tree_view()[0]);
@@ -117,9 +114,9 @@ fn extended_while() {
 
     for test in tests {
         let tokenizer = Lexer::new(NoLogger);
-        let scope = tokenizer.tokenize(test.code_lines);
+        let stack = tokenizer.tokenize(test.code_lines).map(|scope| scope.stack).unwrap_or_default();
 
-        assert_eq!(scope.stack.len(), test.while_count as usize);
+        assert_eq!(stack.len(), test.while_count as usize);
     }
 }
 
@@ -161,11 +158,11 @@ fn nested_while_loop() {
 
     for test in tests {
         let tokenizer = Lexer::new(NoLogger);
-        let scope = tokenizer.tokenize(test.code_lines);
+        let stack = tokenizer.tokenize(test.code_lines).map(|scope| scope.stack).unwrap_or_default();
 
         let mut nesting_count = 0;
 
-        let while_token  = if test.while_count > 0 { scope.stack.first() } else { None };
+        let while_token  = if test.while_count > 0 { stack.first() } else { None };
         if let Some(while_token) = while_token {
             let mut while_token: Option<&WhileToken> = match while_token {
                 Stackable::WhileToken { value } => Some(value),
@@ -178,12 +175,9 @@ fn nested_while_loop() {
                 while_token = {
                     let mut found = None;
                     for stackable in &while_token.unwrap().scope.as_ref().unwrap().stack {
-                        match stackable {
-                            Stackable::WhileToken { value } =>  {
-                                found = Some(value);
-                                break;
-                            },
-                            _ => { }
+                        if let Stackable::WhileToken { value } = stackable {
+                            found = Some(value);
+                            break;
                         }
                     }
 
@@ -196,4 +190,60 @@ fn nested_while_loop() {
 
         assert_eq!(test.while_count as usize, nesting_count);
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn extended_conditions() {
+    let tests = [
+        ("while a != 0:", true),
+        ("while a == b:", false),
+        ("while a < 10:", false),
+        ("while Add(a, 1) >= b and not c <= 3 or d > 0:", false),
+        ("while not (a == 1 or b == 2):", false),
+        ("while (a == 1):", false),
+    ];
+
+    for (header, classic) in tests {
+        let code_lines = vec![CodeLine::new(header, 1), CodeLine::new("#", 2)];
+
+        let extended = Lexer::with_dialect(NoLogger, Dialect::Extended).tokenize(code_lines.clone());
+        assert_eq!(extended.map(|scope| scope.stack.len()), Ok(1), "{}", header);
+
+        let errors = Lexer::new(NoLogger).tokenize(code_lines).map(|scope| scope.stack.len());
+        assert_eq!(errors.is_ok(), classic, "{}", header);
+    }
+
+    let invalid = [
+        "while a:",
+        "while a < :",
+        "while a == 1 and:",
+        "while (a == 1:",
+        "while not:",
+    ];
+
+    for header in invalid {
+        let code_lines = vec![CodeLine::new(header, 1), CodeLine::new("#", 2)];
+        let errors = Lexer::with_dialect(NoLogger, Dialect::Extended).tokenize(code_lines).err().unwrap();
+
+        assert_eq!(errors.len(), 1, "{}", header);
+    }
+}
+
+#[test]
+fn condition_tree_view() {
+    let tests = [
+        ("while a != 0:", "Header: {while condition: {Condition: {Value: a} != {Value: 0}}}"),
+        ("while a < Add(b, 1):", "Header: {while condition: {Condition: {Value: a} < Method call: Add, parameters: [{Value: b}, {Value: 1}]}}"),
+        ("while not (a == 1 or b >= 2) and c > 0:", "Header: {while condition: {Condition: ((not ({Value: a} == {Value: 1} or {Value: b} >= {Value: 2})) and {Value: c} > {Value: 0})}}"),
+    ];
+
+    for (header, expected) in tests {
+        let code_lines = vec![CodeLine::new(header, 1), CodeLine::new("#", 2)];
+        let scope = Lexer::with_dialect(NoLogger, Dialect::Extended).tokenize(code_lines).unwrap();
+
+        match scope.stack.first() {
+            Some(Stackable::WhileToken { value }) => assert_eq!(value.header_token.to_tree_view()[0], expected, "{}", header),
+            stackable => panic!("Expected a while loop, but found: {:?}", stackable)
+        }
+    }
+}